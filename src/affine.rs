@@ -0,0 +1,377 @@
+//! Affine matrix math for objects and backgrounds.
+//!
+//! Affine objects and the affine backgrounds (BG2 and BG3) are drawn using a
+//! 2x2 matrix `P = [pa pb; pc pd]` that maps *screen* pixels to *texture*
+//! pixels. That's the inverse of the transformation that you'd usually think
+//! of (texture to screen). So the normal steps are:
+//! 1. Build the texture to screen transformation out of rotations, scales,
+//!    and shears.
+//! 2. Call [`AffineMatrix::inverse`] on it.
+//! 3. Write the result to `OAM` or to the BG2/BG3 registers.
+//!
+//! An [`AffineMatrix`] holds 16.16 fixed point values, and the hardware uses
+//! 8.8 fixed point ([`I8F8`]). Converting to the hardware format only happens
+//! when the matrix is written, so that building a matrix out of several steps
+//! doesn't lose precision along the way.
+//!
+//! Angles are `u16` values where `0x1_0000` would be a full turn, like the
+//! BIOS `ObjAffineSet` function. Only the upper 8 bits are used.
+
+use super::*;
+
+/// `sin` of each of 256 angles around the circle, in 16.16 fixed point.
+///
+/// Each value is `round(sin(i * 2pi / 256) * 65536)`.
+const SIN_TABLE: [i32; 256] = [
+  0, 1608, 3216, 4821, 6424, 8022, 9616, 11204, 12785, 14359, 15924, 17479,
+  19024, 20557, 22078, 23586, 25080, 26558, 28020, 29466, 30893, 32303, 33692,
+  35062, 36410, 37736, 39040, 40320, 41576, 42806, 44011, 45190, 46341, 47464,
+  48559, 49624, 50660, 51665, 52639, 53581, 54491, 55368, 56212, 57022, 57798,
+  58538, 59244, 59914, 60547, 61145, 61705, 62228, 62714, 63162, 63572, 63944,
+  64277, 64571, 64827, 65043, 65220, 65358, 65457, 65516, 65536, 65516, 65457,
+  65358, 65220, 65043, 64827, 64571, 64277, 63944, 63572, 63162, 62714, 62228,
+  61705, 61145, 60547, 59914, 59244, 58538, 57798, 57022, 56212, 55368, 54491,
+  53581, 52639, 51665, 50660, 49624, 48559, 47464, 46341, 45190, 44011, 42806,
+  41576, 40320, 39040, 37736, 36410, 35062, 33692, 32303, 30893, 29466, 28020,
+  26558, 25080, 23586, 22078, 20557, 19024, 17479, 15924, 14359, 12785, 11204,
+  9616, 8022, 6424, 4821, 3216, 1608, 0, -1608, -3216, -4821, -6424, -8022,
+  -9616, -11204, -12785, -14359, -15924, -17479, -19024, -20557, -22078,
+  -23586, -25080, -26558, -28020, -29466, -30893, -32303, -33692, -35062,
+  -36410, -37736, -39040, -40320, -41576, -42806, -44011, -45190, -46341,
+  -47464, -48559, -49624, -50660, -51665, -52639, -53581, -54491, -55368,
+  -56212, -57022, -57798, -58538, -59244, -59914, -60547, -61145, -61705,
+  -62228, -62714, -63162, -63572, -63944, -64277, -64571, -64827, -65043,
+  -65220, -65358, -65457, -65516, -65536, -65516, -65457, -65358, -65220,
+  -65043, -64827, -64571, -64277, -63944, -63572, -63162, -62714, -62228,
+  -61705, -61145, -60547, -59914, -59244, -58538, -57798, -57022, -56212,
+  -55368, -54491, -53581, -52639, -51665, -50660, -49624, -48559, -47464,
+  -46341, -45190, -44011, -42806, -41576, -40320, -39040, -37736, -36410,
+  -35062, -33692, -32303, -30893, -29466, -28020, -26558, -25080, -23586,
+  -22078, -20557, -19024, -17479, -15924, -14359, -12785, -11204, -9616, -8022,
+  -6424, -4821, -3216, -1608,
+];
+
+/// The sine of an angle, in 16.16 fixed point.
+pub const fn fixed_sin(angle: u16) -> i32 {
+  SIN_TABLE[(angle >> 8) as usize]
+}
+
+/// The cosine of an angle, in 16.16 fixed point.
+pub const fn fixed_cos(angle: u16) -> i32 {
+  fixed_sin(angle.wrapping_add(0x4000))
+}
+
+/// Multiplies two 16.16 values.
+const fn mul_16_16(a: i32, b: i32) -> i32 {
+  ((a as i64 * b as i64) >> 16) as i32
+}
+
+/// A 2x2 matrix of 16.16 fixed point values.
+///
+/// The matrix is `[pa pb; pc pd]`, and it maps a point `(x, y)` to
+/// `(pa*x + pb*y, pc*x + pd*y)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AffineMatrix {
+  /// The `pa` value.
+  pub pa: i32,
+  /// The `pb` value.
+  pub pb: i32,
+  /// The `pc` value.
+  pub pc: i32,
+  /// The `pd` value.
+  pub pd: i32,
+}
+impl Default for AffineMatrix {
+  fn default() -> Self {
+    Self::IDENTITY
+  }
+}
+impl AffineMatrix {
+  /// The matrix that leaves points unchanged.
+  pub const IDENTITY: Self = Self {
+    pa: 0x1_0000,
+    pb: 0,
+    pc: 0,
+    pd: 0x1_0000,
+  };
+
+  /// A counter-clockwise rotation (as seen on the screen).
+  pub const fn rotation(angle: u16) -> Self {
+    let sin = fixed_sin(angle);
+    let cos = fixed_cos(angle);
+    Self {
+      pa: cos,
+      pb: sin,
+      pc: -sin,
+      pd: cos,
+    }
+  }
+
+  /// A scale by `sx` horizontally and `sy` vertically, in 16.16 fixed point.
+  pub const fn scale(sx: i32, sy: i32) -> Self {
+    Self {
+      pa: sx,
+      pb: 0,
+      pc: 0,
+      pd: sy,
+    }
+  }
+
+  /// A shear by `hx` horizontally and `hy` vertically, in 16.16 fixed point.
+  ///
+  /// A horizontal shear moves each point right by `hx` times its `y`.
+  pub const fn shear(hx: i32, hy: i32) -> Self {
+    Self {
+      pa: 0x1_0000,
+      pb: hx,
+      pc: hy,
+      pd: 0x1_0000,
+    }
+  }
+
+  /// The matrix that applies `other` first, and then `self`.
+  pub const fn mul(self, other: Self) -> Self {
+    Self {
+      pa: mul_16_16(self.pa, other.pa) + mul_16_16(self.pb, other.pc),
+      pb: mul_16_16(self.pa, other.pb) + mul_16_16(self.pb, other.pd),
+      pc: mul_16_16(self.pc, other.pa) + mul_16_16(self.pd, other.pc),
+      pd: mul_16_16(self.pc, other.pb) + mul_16_16(self.pd, other.pd),
+    }
+  }
+
+  /// The inverse matrix.
+  ///
+  /// Returns `None` if the matrix can't be inverted, or if any value of the
+  /// inverse is too large for 16.16 fixed point.
+  pub const fn inverse(self) -> Option<Self> {
+    // The determinant has 32 fractional bits.
+    let det =
+      self.pa as i128 * self.pd as i128 - self.pb as i128 * self.pc as i128;
+    if det == 0 {
+      return None;
+    }
+    // Each value has 16 fractional bits, shifting by 32 and then dividing by
+    // the determinant gives 16 fractional bits again.
+    const fn div(v: i128, det: i128) -> Option<i32> {
+      let q = (v << 32) / det;
+      if q < i32::MIN as i128 || q > i32::MAX as i128 {
+        None
+      } else {
+        Some(q as i32)
+      }
+    }
+    match (
+      div(self.pd as i128, det),
+      div(-(self.pb as i128), det),
+      div(-(self.pc as i128), det),
+      div(self.pa as i128, det),
+    ) {
+      (Some(pa), Some(pb), Some(pc), Some(pd)) => Some(Self { pa, pb, pc, pd }),
+      _ => None,
+    }
+  }
+
+  /// Applies the matrix to a point, in any fixed point format.
+  ///
+  /// The output uses the same format as the input.
+  pub const fn apply(self, x: i32, y: i32) -> (i32, i32) {
+    (
+      mul_16_16(self.pa, x) + mul_16_16(self.pb, y),
+      mul_16_16(self.pc, x) + mul_16_16(self.pd, y),
+    )
+  }
+
+  /// The affine parameters for `OAM`, in 8.8 fixed point.
+  ///
+  /// Values are rounded to the nearest 8.8 value, and values outside the 8.8
+  /// range are saturated.
+  pub const fn to_obj_params(self) -> ObjAffineParams {
+    ObjAffineParams {
+      pa: I8F8::from_16_16(self.pa),
+      pb: I8F8::from_16_16(self.pb),
+      pc: I8F8::from_16_16(self.pc),
+      pd: I8F8::from_16_16(self.pd),
+    }
+  }
+
+  /// The background reference point that places the texture point `tex` at
+  /// the screen pixel `screen`, using this matrix as the screen to texture
+  /// matrix.
+  ///
+  /// The output is saturated to the range of [`I20F8`].
+  pub const fn bg_ref_point(
+    self,
+    tex: (I20F8, I20F8),
+    screen: (i32, i32),
+  ) -> (I20F8, I20F8) {
+    let (dx, dy) = self.apply(screen.0 << 8, screen.1 << 8);
+    (
+      I20F8::from_bits(tex.0.to_bits().saturating_sub(dx)),
+      I20F8::from_bits(tex.1.to_bits().saturating_sub(dy)),
+    )
+  }
+
+  /// The `(address, value)` of each 16-bit write that places this matrix in
+  /// a given affine parameter entry of `OAM`.
+  ///
+  /// ## Panics
+  /// `i` must be < 32.
+  pub const fn obj_writes(self, i: usize) -> [(usize, u16); 4] {
+    let p = self.to_obj_params();
+    let addr = index_obj_affine_param(i);
+    [
+      (addr, p.pa.to_register_bits()),
+      (addr + OBJ_AFFINE_FIELD_STRIDE, p.pb.to_register_bits()),
+      (addr + 2 * OBJ_AFFINE_FIELD_STRIDE, p.pc.to_register_bits()),
+      (addr + 3 * OBJ_AFFINE_FIELD_STRIDE, p.pd.to_register_bits()),
+    ]
+  }
+
+  /// Writes the matrix to a given affine parameter entry of `OAM`.
+  ///
+  /// To avoid tearing, use a [`ShadowOam`] instead.
+  ///
+  /// ## Panics
+  /// `i` must be < 32.
+  ///
+  /// ## Safety
+  /// This must run on the GBA (or an emulator), where `OAM` is mapped.
+  pub unsafe fn write_obj(self, i: usize) {
+    for (addr, value) in self.obj_writes(i).iter() {
+      (*addr as *mut u16).write_volatile(*value);
+    }
+  }
+
+  /// The `(address, value)` of each 16-bit write that places this matrix in
+  /// the `PA` through `PD` registers of BG2 or BG3.
+  ///
+  /// See [`bg_ref_point_writes`] for the `X` and `Y` registers.
+  ///
+  /// ## Panics
+  /// `bg` must be 2 or 3.
+  pub const fn bg_writes(self, bg: usize) -> [(usize, u16); 4] {
+    let p = self.to_obj_params();
+    let addr = bg_affine_addr(bg);
+    [
+      (addr, p.pa.to_register_bits()),
+      (addr + 2, p.pb.to_register_bits()),
+      (addr + 4, p.pc.to_register_bits()),
+      (addr + 6, p.pd.to_register_bits()),
+    ]
+  }
+
+  /// Writes the matrix and the reference point `(x, y)` to the registers of
+  /// BG2 or BG3.
+  ///
+  /// ## Panics
+  /// `bg` must be 2 or 3.
+  ///
+  /// ## Safety
+  /// This must run on the GBA (or an emulator), where the IO registers are
+  /// mapped.
+  pub unsafe fn write_bg(self, bg: usize, x: I20F8, y: I20F8) {
+    for (addr, value) in self.bg_writes(bg).iter() {
+      (*addr as *mut u16).write_volatile(*value);
+    }
+    for (addr, value) in bg_ref_point_writes(bg, x, y).iter() {
+      (*addr as *mut u32).write_volatile(*value);
+    }
+  }
+}
+
+/// The distance between the `pa`, `pb`, `pc`, and `pd` values of an `OAM`
+/// affine parameter entry.
+const OBJ_AFFINE_FIELD_STRIDE: usize =
+  OBJ_AFFINE_PB_BASE_ADDR - OBJ_AFFINE_PA_BASE_ADDR;
+
+/// The distance from a background's `PA` register to its `X` register.
+const BG_AFFINE_X_OFFSET: usize = BG2X_ADDR - BG2PA_ADDR;
+
+/// The `(address, value)` of each 32-bit write that sets the reference point
+/// `(x, y)` of BG2 or BG3.
+///
+/// ## Panics
+/// `bg` must be 2 or 3.
+pub const fn bg_ref_point_writes(
+  bg: usize,
+  x: I20F8,
+  y: I20F8,
+) -> [(usize, u32); 2] {
+  let addr = bg_affine_addr(bg) + BG_AFFINE_X_OFFSET;
+  [
+    (addr, x.to_register_bits()),
+    (addr + 4, y.to_register_bits()),
+  ]
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_obj_writes() {
+    let writes = AffineMatrix::IDENTITY.obj_writes(1);
+    assert_eq!(
+      writes,
+      [
+        (0x0700_0026, 0x0100),
+        (0x0700_002E, 0),
+        (0x0700_0036, 0),
+        (0x0700_003E, 0x0100),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_bg_writes() {
+    let m = AffineMatrix::scale(0x2_0000, -0x1_0000);
+    let x = I20F8::from_int(-1);
+    let y = I20F8::from_int(3);
+    assert_eq!(
+      m.bg_writes(3),
+      [
+        (BG3PA_ADDR, 0x0200),
+        (BG3PB_ADDR, 0),
+        (BG3PC_ADDR, 0),
+        (BG3PD_ADDR, 0xFF00),
+      ]
+    );
+    assert_eq!(
+      bg_ref_point_writes(3, x, y),
+      [(BG3X_ADDR, 0x0FFF_FF00), (BG3Y_ADDR, 0x300)]
+    );
+  }
+
+  #[test]
+  fn test_inverse() {
+    assert_eq!(
+      AffineMatrix::IDENTITY.inverse(),
+      Some(AffineMatrix::IDENTITY)
+    );
+    assert_eq!(
+      AffineMatrix::scale(0x2_0000, 0x8000).inverse(),
+      Some(AffineMatrix::scale(0x8000, 0x2_0000))
+    );
+    assert_eq!(AffineMatrix::scale(0, 0x1_0000).inverse(), None);
+  }
+
+  #[test]
+  fn test_inverse_overflow() {
+    assert_eq!(AffineMatrix::scale(1, 1).inverse(), None);
+    assert_eq!(AffineMatrix::scale(1, 0x1_0000).inverse(), None);
+  }
+
+  #[test]
+  fn test_rotation_inverse() {
+    for angle in (0..=0xFFFF_u16).step_by(0x100) {
+      let r = AffineMatrix::rotation(angle);
+      let m = r.mul(r.inverse().unwrap());
+      let id = AffineMatrix::IDENTITY;
+      for &(a, b) in
+        &[(m.pa, id.pa), (m.pb, id.pb), (m.pc, id.pc), (m.pd, id.pd)]
+      {
+        assert!((a - b).abs() <= 4, "angle {:#X}: {:?}", angle, m);
+      }
+    }
+  }
+}
@@ -0,0 +1,31 @@
+//! Basic Input/Output System (`BIOS`).
+//!
+//! This holds the system ROM, which provides the startup code, the interrupt
+//! dispatch code, and the various software interrupt functions.
+//!
+//! The `BIOS` can only be read by code that's also running within the `BIOS`.
+//! Reads from outside the `BIOS` get the last value fetched by the `BIOS`
+//! instead.
+//!
+//! * **Size:** 16kb
+//! * **Wait states:** 0
+//! * **Bus Size:** 32-bit
+//! * **Reads:** 8/16/32
+
+use super::*;
+
+/// Base Address of the `BIOS`.
+pub const BIOS_START_ADDR: usize = 0x0000_0000;
+
+/// The `BIOS` is 16kb.
+pub const BIOS_COUNT: usize = 16 * 1024;
+
+/// Access info for the `BIOS`.
+pub const BIOS_REGION_INFO: RegionInfo = RegionInfo {
+  bus_width: AccessWidth::Bits32,
+  reads: AccessWidths::ALL,
+  writes: AccessWidths::NONE,
+  wait_states: 0,
+  dma_source: false,
+  dma_destination: false,
+};
@@ -0,0 +1,227 @@
+//! Color values for `PALRAM` and the bitmap video modes.
+//!
+//! A [`Color`] has 5 bits per channel. The blending functions use the same
+//! math as the display hardware's special effects, so you can compute in
+//! advance the colors that the display will show, or fade a palette in
+//! software.
+//!
+//! The hardware's blend coefficients (`EVA`, `EVB`, and `EVY`) are [`U1F4`]
+//! values, from 0 through 1.0 in 16ths.
+
+use super::*;
+
+/// An RGB555 color.
+///
+/// * 0-4: Red
+/// * 5-9: Green
+/// * 10-14: Blue
+///
+/// Bit 15 is ignored by the hardware.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Color(u16);
+impl Color {
+  u16_newtype_basics!();
+
+  /// Info about each field.
+  pub const FIELDS: &'static [FieldInfo] = &[
+    FieldInfo::new("red", 0, 5),
+    FieldInfo::new("green", 5, 5),
+    FieldInfo::new("blue", 10, 5),
+  ];
+
+  /// Black.
+  pub const BLACK: Self = Self::from_rgb(0, 0, 0);
+  /// Red.
+  pub const RED: Self = Self::from_rgb(31, 0, 0);
+  /// Green.
+  pub const GREEN: Self = Self::from_rgb(0, 31, 0);
+  /// Blue.
+  pub const BLUE: Self = Self::from_rgb(0, 0, 31);
+  /// Yellow.
+  pub const YELLOW: Self = Self::from_rgb(31, 31, 0);
+  /// Cyan.
+  pub const CYAN: Self = Self::from_rgb(0, 31, 31);
+  /// Magenta.
+  pub const MAGENTA: Self = Self::from_rgb(31, 0, 31);
+  /// White.
+  pub const WHITE: Self = Self::from_rgb(31, 31, 31);
+
+  /// Makes a color from 5-bit channels.
+  ///
+  /// Any bits of a channel above the lowest 5 are discarded.
+  pub const fn from_rgb(r: u16, g: u16, b: u16) -> Self {
+    Self::new().with_red(r).with_green(g).with_blue(b)
+  }
+
+  /// Makes a color from 8-bit channels.
+  ///
+  /// The lowest 3 bits of each channel are discarded.
+  pub const fn from_rgb8(r: u8, g: u8, b: u8) -> Self {
+    Self::from_rgb((r >> 3) as u16, (g >> 3) as u16, (b >> 3) as u16)
+  }
+
+  /// Makes a color from a `0xRRGGBB` value, as is common in image editors.
+  ///
+  /// Any bits above the lowest 24 are discarded.
+  pub const fn from_hex(hex: u32) -> Self {
+    Self::from_rgb8((hex >> 16) as u8, (hex >> 8) as u8, hex as u8)
+  }
+
+  u16_int_field!(
+    /// The red channel, 0 through 31.
+    0 - 4,
+    red,
+    with_red
+  );
+  u16_int_field!(
+    /// The green channel, 0 through 31.
+    5 - 9,
+    green,
+    with_green
+  );
+  u16_int_field!(
+    /// The blue channel, 0 through 31.
+    10 - 14,
+    blue,
+    with_blue
+  );
+
+  /// The 8-bit channels of the color, as `(r, g, b)`.
+  ///
+  /// Each channel is scaled so that 31 becomes 255.
+  pub const fn to_rgb8(self) -> (u8, u8, u8) {
+    const fn expand(c: u16) -> u8 {
+      ((c << 3) | (c >> 2)) as u8
+    }
+    (
+      expand(self.red()),
+      expand(self.green()),
+      expand(self.blue()),
+    )
+  }
+
+  /// Alpha blends this color (the first target) with `other` (the second
+  /// target).
+  ///
+  /// Each channel is `min(31, (self * eva + other * evb) / 16)`, as with
+  /// [`BLDALPHA_A_ADDR`] and [`BLDALPHA_B_ADDR`].
+  pub const fn blend(self, other: Self, eva: U1F4, evb: U1F4) -> Self {
+    const fn channel(a: u16, b: u16, eva: u16, evb: u16) -> u16 {
+      let c = (a * eva + b * evb) >> 4;
+      if c > 31 {
+        31
+      } else {
+        c
+      }
+    }
+    let eva = eva.to_bits() as u16;
+    let evb = evb.to_bits() as u16;
+    Self::from_rgb(
+      channel(self.red(), other.red(), eva, evb),
+      channel(self.green(), other.green(), eva, evb),
+      channel(self.blue(), other.blue(), eva, evb),
+    )
+  }
+
+  /// Moves this color towards white by `evy`, as with [`BLDY_ADDR`].
+  ///
+  /// Each channel is `c + (31 - c) * evy / 16`.
+  pub const fn brighten(self, evy: U1F4) -> Self {
+    const fn channel(c: u16, evy: u16) -> u16 {
+      c + (((31 - c) * evy) >> 4)
+    }
+    let evy = evy.to_bits() as u16;
+    Self::from_rgb(
+      channel(self.red(), evy),
+      channel(self.green(), evy),
+      channel(self.blue(), evy),
+    )
+  }
+
+  /// Moves this color towards black by `evy`, as with [`BLDY_ADDR`].
+  ///
+  /// Each channel is `c - c * evy / 16`.
+  pub const fn darken(self, evy: U1F4) -> Self {
+    const fn channel(c: u16, evy: u16) -> u16 {
+      c - ((c * evy) >> 4)
+    }
+    let evy = evy.to_bits() as u16;
+    Self::from_rgb(
+      channel(self.red(), evy),
+      channel(self.green(), evy),
+      channel(self.blue(), evy),
+    )
+  }
+}
+
+/// Moves every color of a palette towards white by `evy`.
+pub fn palette_brighten(palette: &mut [Color], evy: U1F4) {
+  for c in palette.iter_mut() {
+    *c = c.brighten(evy);
+  }
+}
+
+/// Moves every color of a palette towards black by `evy`.
+pub fn palette_darken(palette: &mut [Color], evy: U1F4) {
+  for c in palette.iter_mut() {
+    *c = c.darken(evy);
+  }
+}
+
+/// Alpha blends every color of a palette with the matching color of `other`.
+///
+/// If the palettes are different lengths, the extra colors of the longer one
+/// are ignored.
+pub fn palette_blend(
+  palette: &mut [Color],
+  other: &[Color],
+  eva: U1F4,
+  evb: U1F4,
+) {
+  for (c, o) in palette.iter_mut().zip(other.iter()) {
+    *c = c.blend(*o, eva, evb);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_from_rgb8() {
+    assert_eq!(Color::from_rgb8(255, 7, 8), Color::from_rgb(31, 0, 1));
+    assert_eq!(Color::from_hex(0xFF8000), Color::from_rgb(31, 16, 0));
+    assert_eq!(Color::from_hex(0x12_FFFFFF), Color::WHITE);
+    assert_eq!(Color::WHITE.to_rgb8(), (255, 255, 255));
+    assert_eq!(Color::from_rgb(16, 1, 0).to_rgb8(), (132, 8, 0));
+  }
+
+  #[test]
+  fn test_blend() {
+    let one = U1F4::ONE;
+    let half = U1F4::from_bits(8);
+    assert_eq!(Color::WHITE.blend(Color::WHITE, one, one), Color::WHITE);
+    assert_eq!(Color::RED.blend(Color::BLUE, one, one), Color::MAGENTA);
+    assert_eq!(
+      Color::from_rgb(20, 10, 0).blend(Color::from_rgb(10, 10, 31), half, half),
+      Color::from_rgb(15, 10, 15)
+    );
+    assert_eq!(Color::RED.blend(Color::BLUE, U1F4::ZERO, one), Color::BLUE);
+  }
+
+  #[test]
+  fn test_brighten_darken() {
+    let c = Color::from_rgb(0, 15, 31);
+    assert_eq!(c.brighten(U1F4::ONE), Color::WHITE);
+    assert_eq!(c.darken(U1F4::ONE), Color::BLACK);
+    assert_eq!(c.brighten(U1F4::ZERO), c);
+    assert_eq!(c.darken(U1F4::ZERO), c);
+    let half = U1F4::from_bits(8);
+    assert_eq!(c.brighten(half), Color::from_rgb(15, 23, 31));
+    assert_eq!(
+      Color::from_rgb(31, 15, 1).darken(half),
+      Color::from_rgb(16, 8, 1)
+    );
+  }
+}
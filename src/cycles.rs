@@ -0,0 +1,135 @@
+//! Estimating the cycle cost of memory accesses.
+//!
+//! Each access to a region costs 1 cycle plus that region's wait states. If an
+//! access is wider than the region's bus, it's split into more than one access,
+//! and the later parts are always sequential.
+//!
+//! The wait states of `ROM` and `SRAM` depend on the value of
+//! [`WAITCNT_ADDR`]. `ROM` accesses also depend on if the access is
+//! sequential (the next address after the previous access) or non-sequential.
+//! The default [`WaitControl`] value (all bits 0) is the setting at boot:
+//! 4 wait states for `SRAM`, and 4 wait states for the first access to each
+//! `ROM` mirror, with 2 (WS0), 4 (WS1), or 8 (WS2) for later sequential
+//! accesses.
+//!
+//! This is an estimate only. It does *not* account for the `ROM` prefetch
+//! buffer, or for any delay from accessing video memory while the display is
+//! also using it.
+
+use super::*;
+
+/// The number of cycles for a single access.
+///
+/// * `region`: The region being accessed.
+/// * `width`: The width of the access.
+/// * `sequential`: If the access directly follows the previous access.
+/// * `waitcnt`: The current value of [`WAITCNT_ADDR`].
+pub const fn access_cycles(
+  region: MemoryRegion,
+  width: AccessWidth,
+  sequential: bool,
+  waitcnt: WaitControl,
+) -> u32 {
+  let info = region.info();
+  if let Some(ws) = region.rom_wait_state() {
+    let n = waitcnt.first_access_waits(ws);
+    let s = waitcnt.second_access_waits(ws);
+    let first = 1 + if sequential { s } else { n };
+    match width {
+      AccessWidth::Bits32 => first + 1 + s,
+      _ => first,
+    }
+  } else if let MemoryRegion::Sram = region {
+    1 + waitcnt.sram_waits()
+  } else {
+    let parts = if width.bytes() > info.bus_width.bytes() {
+      width.bytes() / info.bus_width.bytes()
+    } else {
+      1
+    };
+    parts as u32 * (1 + info.wait_states as u32)
+  }
+}
+
+/// The number of cycles for a run of `count` accesses to consecutive
+/// addresses.
+///
+/// The first access is non-sequential, and the rest are sequential.
+pub const fn run_cycles(
+  region: MemoryRegion,
+  width: AccessWidth,
+  count: u32,
+  waitcnt: WaitControl,
+) -> u32 {
+  if count == 0 {
+    0
+  } else {
+    access_cycles(region, width, false, waitcnt)
+      + (count - 1) * access_cycles(region, width, true, waitcnt)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_access_cycles() {
+    use AccessWidth::*;
+    use MemoryRegion::*;
+    let boot = WaitControl::new();
+    for &(region, width, sequential, cycles) in &[
+      (Bios, Bits32, false, 1),
+      (Ewram, Bits8, false, 3),
+      (Ewram, Bits16, false, 3),
+      (Ewram, Bits32, false, 6),
+      (Iwram, Bits32, false, 1),
+      (Io, Bits32, false, 1),
+      (Palram, Bits16, false, 1),
+      (Palram, Bits32, false, 2),
+      (Vram, Bits32, false, 2),
+      (Oam, Bits32, false, 1),
+      (RomWait0, Bits16, false, 5),
+      (RomWait0, Bits16, true, 3),
+      (RomWait0, Bits32, false, 8),
+      (RomWait0, Bits32, true, 6),
+      (RomWait1, Bits16, true, 5),
+      (RomWait2, Bits16, true, 9),
+      (RomWait2, Bits32, false, 14),
+      (Sram, Bits8, false, 5),
+    ] {
+      assert_eq!(
+        access_cycles(region, width, sequential, boot),
+        cycles,
+        "{:?} {:?} {}",
+        region,
+        width,
+        sequential
+      );
+    }
+  }
+
+  #[test]
+  fn test_access_cycles_waitcnt() {
+    use AccessWidth::*;
+    use MemoryRegion::*;
+    let fast = WaitControl::from_u16(0x4317);
+    assert_eq!(access_cycles(RomWait0, Bits16, false, fast), 4);
+    assert_eq!(access_cycles(RomWait0, Bits16, true, fast), 2);
+    assert_eq!(access_cycles(RomWait0, Bits32, false, fast), 6);
+    assert_eq!(access_cycles(Sram, Bits8, false, fast), 9);
+    // WAITCNT doesn't change the other regions
+    assert_eq!(access_cycles(Ewram, Bits32, false, fast), 6);
+  }
+
+  #[test]
+  fn test_run_cycles() {
+    use AccessWidth::*;
+    use MemoryRegion::*;
+    let boot = WaitControl::new();
+    assert_eq!(run_cycles(RomWait2, Bits16, 3, boot), 23);
+    assert_eq!(run_cycles(RomWait0, Bits32, 2, boot), 14);
+    assert_eq!(run_cycles(Iwram, Bits32, 4, boot), 4);
+    assert_eq!(run_cycles(Ewram, Bits16, 0, boot), 0);
+  }
+}
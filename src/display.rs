@@ -0,0 +1,327 @@
+//! Display and background control values.
+//!
+//! These types give names to the bits of the [`DISPCNT_ADDR`],
+//! [`DISPSTAT_ADDR`], and [`BG0CNT_ADDR`] through [`BG3CNT_ADDR`] registers.
+//! Each is a `u16` newtype with `const fn` getters, and `with_` setters that
+//! return a new value, so they can be used to build values at compile time.
+
+use super::*;
+
+/// A value for [`DISPCNT_ADDR`].
+///
+/// * 0-2: Video mode (0 through 5)
+/// * 3: CGB mode (read-only, GBA BIOS only)
+/// * 4: Display frame 1 (video modes 4 and 5)
+/// * 5: Allow `OAM` access during HBlank
+/// * 6: Object tiles use 1D mapping (otherwise 2D)
+/// * 7: Forced blank
+/// * 8: Display BG0
+/// * 9: Display BG1
+/// * 10: Display BG2
+/// * 11: Display BG3
+/// * 12: Display objects
+/// * 13: Enable window 0
+/// * 14: Enable window 1
+/// * 15: Enable the object window
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct DisplayControl(u16);
+impl DisplayControl {
+  u16_newtype_basics!();
+
+  /// Info about each field.
+  pub const FIELDS: &'static [FieldInfo] = &[
+    FieldInfo::new("video_mode", 0, 3),
+    FieldInfo::new("cgb_mode", 3, 1),
+    FieldInfo::new("frame1", 4, 1),
+    FieldInfo::new("hblank_oam_free", 5, 1),
+    FieldInfo::new("obj_vram_1d", 6, 1),
+    FieldInfo::new("forced_blank", 7, 1),
+    FieldInfo::new("display_bg0", 8, 1),
+    FieldInfo::new("display_bg1", 9, 1),
+    FieldInfo::new("display_bg2", 10, 1),
+    FieldInfo::new("display_bg3", 11, 1),
+    FieldInfo::new("display_obj", 12, 1),
+    FieldInfo::new("display_win0", 13, 1),
+    FieldInfo::new("display_win1", 14, 1),
+    FieldInfo::new("display_obj_win", 15, 1),
+  ];
+
+  u16_int_field!(
+    /// The video mode, 0 through 5.
+    0 - 2,
+    video_mode,
+    with_video_mode
+  );
+  u16_bool_field!(
+    /// If the display is in CGB mode.
+    ///
+    /// This can only be set by the BIOS.
+    3,
+    cgb_mode,
+    with_cgb_mode
+  );
+  u16_bool_field!(
+    /// If frame 1 is displayed (otherwise frame 0). Video modes 4 and 5.
+    4,
+    frame1,
+    with_frame1
+  );
+  u16_bool_field!(
+    /// If `OAM` can be accessed during HBlank.
+    5,
+    hblank_oam_free,
+    with_hblank_oam_free
+  );
+  u16_bool_field!(
+    /// If object tiles use 1D mapping (otherwise 2D mapping).
+    6,
+    obj_vram_1d,
+    with_obj_vram_1d
+  );
+  u16_bool_field!(
+    /// If the display is forced blank.
+    7,
+    forced_blank,
+    with_forced_blank
+  );
+  u16_bool_field!(
+    /// If BG0 is displayed.
+    8,
+    display_bg0,
+    with_display_bg0
+  );
+  u16_bool_field!(
+    /// If BG1 is displayed.
+    9,
+    display_bg1,
+    with_display_bg1
+  );
+  u16_bool_field!(
+    /// If BG2 is displayed.
+    10,
+    display_bg2,
+    with_display_bg2
+  );
+  u16_bool_field!(
+    /// If BG3 is displayed.
+    11,
+    display_bg3,
+    with_display_bg3
+  );
+  u16_bool_field!(
+    /// If objects are displayed.
+    12,
+    display_obj,
+    with_display_obj
+  );
+  u16_bool_field!(
+    /// If window 0 is enabled.
+    13,
+    display_win0,
+    with_display_win0
+  );
+  u16_bool_field!(
+    /// If window 1 is enabled.
+    14,
+    display_win1,
+    with_display_win1
+  );
+  u16_bool_field!(
+    /// If the object window is enabled.
+    15,
+    display_obj_win,
+    with_display_obj_win
+  );
+
+  /// The base address of the bitmap frame selected by
+  /// [`frame1`](Self::frame1).
+  ///
+  /// Only meaningful in video modes 4 and 5.
+  pub const fn bitmap_frame_addr(self) -> usize {
+    if self.frame1() {
+      VRAM_MODE4_FRAME1_BASE_ADDR
+    } else {
+      VRAM_FRAME0_BASE_ADDR
+    }
+  }
+}
+
+/// A value for [`DISPSTAT_ADDR`].
+///
+/// * 0: Currently in VBlank (read-only)
+/// * 1: Currently in HBlank (read-only)
+/// * 2: `VCOUNT` matches the VCount setting (read-only)
+/// * 3: VBlank interrupt enabled
+/// * 4: HBlank interrupt enabled
+/// * 5: VCount interrupt enabled
+/// * 8-15: VCount setting
+///
+/// The read-only bits are ignored when written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct DisplayStatus(u16);
+impl DisplayStatus {
+  u16_newtype_basics!();
+
+  /// Info about each field.
+  pub const FIELDS: &'static [FieldInfo] = &[
+    FieldInfo::new("vblank", 0, 1),
+    FieldInfo::new("hblank", 1, 1),
+    FieldInfo::new("vcount", 2, 1),
+    FieldInfo::new("vblank_irq", 3, 1),
+    FieldInfo::new("hblank_irq", 4, 1),
+    FieldInfo::new("vcount_irq", 5, 1),
+    FieldInfo::new("vcount_setting", 8, 8),
+  ];
+
+  u16_bool_field!(
+    /// If the display is currently in VBlank.
+    0,
+    vblank,
+    with_vblank
+  );
+  u16_bool_field!(
+    /// If the display is currently in HBlank.
+    1,
+    hblank,
+    with_hblank
+  );
+  u16_bool_field!(
+    /// If `VCOUNT` currently matches the
+    /// [`vcount_setting`](Self::vcount_setting).
+    2,
+    vcount,
+    with_vcount
+  );
+  u16_bool_field!(
+    /// If the VBlank interrupt is enabled.
+    3,
+    vblank_irq,
+    with_vblank_irq
+  );
+  u16_bool_field!(
+    /// If the HBlank interrupt is enabled.
+    4,
+    hblank_irq,
+    with_hblank_irq
+  );
+  u16_bool_field!(
+    /// If the VCount interrupt is enabled.
+    5,
+    vcount_irq,
+    with_vcount_irq
+  );
+  u16_int_field!(
+    /// The scanline that triggers the VCount flag and interrupt.
+    8 - 15,
+    vcount_setting,
+    with_vcount_setting
+  );
+}
+
+/// A value for [`BG0CNT_ADDR`] through [`BG3CNT_ADDR`].
+///
+/// * 0-1: Priority (0 is drawn on top)
+/// * 2-3: Charblock base (0 through 3)
+/// * 6: Mosaic
+/// * 7: Use 8bpp tiles (otherwise 4bpp, affine is always 8bpp)
+/// * 8-12: Screenblock base (0 through 31)
+/// * 13: Affine wrapping (BG2 and BG3 only)
+/// * 14-15: Size
+///
+/// The size values are as follows:
+///
+/// | Size | Text    | Affine  |
+/// |:----:|:-------:|:-------:|
+/// | 0    | 32x32   | 16x16   |
+/// | 1    | 64x32   | 32x32   |
+/// | 2    | 32x64   | 64x64   |
+/// | 3    | 64x64   | 128x128 |
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct BackgroundControl(u16);
+impl BackgroundControl {
+  u16_newtype_basics!();
+
+  /// Info about each field.
+  pub const FIELDS: &'static [FieldInfo] = &[
+    FieldInfo::new("priority", 0, 2),
+    FieldInfo::new("charblock", 2, 2),
+    FieldInfo::new("mosaic", 6, 1),
+    FieldInfo::new("is_8bpp", 7, 1),
+    FieldInfo::new("screenblock", 8, 5),
+    FieldInfo::new("affine_wrap", 13, 1),
+    FieldInfo::new("size", 14, 2),
+  ];
+
+  u16_int_field!(
+    /// The draw priority, 0 through 3. Lower values are drawn on top.
+    0 - 1,
+    priority,
+    with_priority
+  );
+  u16_int_field!(
+    /// The charblock that tile indexes are relative to, 0 through 3.
+    2 - 3,
+    charblock,
+    with_charblock
+  );
+  u16_bool_field!(
+    /// If the mosaic effect is applied.
+    6,
+    mosaic,
+    with_mosaic
+  );
+  u16_bool_field!(
+    /// If the tiles are 8bpp (otherwise 4bpp).
+    ///
+    /// Affine backgrounds are always 8bpp.
+    7,
+    is_8bpp,
+    with_is_8bpp
+  );
+  u16_int_field!(
+    /// The screenblock that the layout starts at, 0 through 31.
+    8 - 12,
+    screenblock,
+    with_screenblock
+  );
+  u16_bool_field!(
+    /// If an affine background wraps around at the edges.
+    13,
+    affine_wrap,
+    with_affine_wrap
+  );
+  u16_int_field!(
+    /// The size, 0 through 3. The meaning depends on text or affine mode.
+    14 - 15,
+    size,
+    with_size
+  );
+
+  /// The address of the [`charblock`](Self::charblock) for this background.
+  pub const fn charblock_addr(self) -> CharblockAddress {
+    index_bg_charblock(self.charblock() as usize)
+  }
+
+  /// The address of the [`screenblock`](Self::screenblock) for this
+  /// background.
+  pub const fn screenblock_addr(self) -> usize {
+    index_screenblock(self.screenblock() as usize)
+  }
+
+  /// The address of the text mode screen entry at tile `(x, y)` of this
+  /// background.
+  ///
+  /// ## Panics
+  /// See [`index_text_screen_entry`].
+  pub const fn text_entry_addr(self, x: usize, y: usize) -> usize {
+    index_text_screen_entry(
+      self.screenblock() as usize,
+      self.size() as usize,
+      x,
+      y,
+    )
+  }
+}
@@ -0,0 +1,315 @@
+//! DMA control values and transfer validation.
+//!
+//! Each of the four DMA units has a source address, a destination address, a
+//! transfer count, and a control register. See [`dma_sad`], [`dma_dad`],
+//! [`dma_cnt_l`], and [`dma_cnt_h`] for the addresses of a given unit.
+//!
+//! The units aren't all the same:
+//! * DMA 0 can't read from `ROM`.
+//! * DMA 0 through 2 can transfer up to `0x4000` units, DMA 3 can transfer up
+//!   to `0x1_0000` units.
+//! * Only DMA 3 can be started by a Game Pak DRQ.
+//!
+//! A [`DmaTransfer`] describes a whole transfer, and can check these limits
+//! before anything is written to the registers.
+
+use super::*;
+
+/// How a DMA unit's address changes after each unit is transferred.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u16)]
+pub enum DmaAddrControl {
+  /// The address increases.
+  Increment = 0,
+  /// The address decreases.
+  Decrement = 1,
+  /// The address stays the same.
+  Fixed = 2,
+  /// The address increases, and is reset when the transfer repeats.
+  ///
+  /// Only valid for the destination address.
+  IncrementReload = 3,
+}
+
+/// When a DMA unit starts its transfer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u16)]
+pub enum DmaStartTiming {
+  /// Starts right away.
+  Immediate = 0,
+  /// Starts at the next VBlank.
+  VBlank = 1,
+  /// Starts at the next HBlank.
+  HBlank = 2,
+  /// Depends on the unit.
+  ///
+  /// * DMA 0: Prohibited.
+  /// * DMA 1 and 2: Sound FIFO.
+  /// * DMA 3: Video capture.
+  Special = 3,
+}
+
+/// A value for [`DMA0CNT_H_ADDR`] through [`DMA3CNT_H_ADDR`].
+///
+/// * 5-6: Destination address control
+/// * 7-8: Source address control
+/// * 9: Repeat
+/// * 10: Transfer 32-bit units (otherwise 16-bit)
+/// * 11: Game Pak DRQ (DMA 3 only)
+/// * 12-13: Start timing
+/// * 14: Interrupt when the transfer is done
+/// * 15: Enable
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct DmaControl(u16);
+impl DmaControl {
+  u16_newtype_basics!();
+
+  /// Info about each field.
+  pub const FIELDS: &'static [FieldInfo] = &[
+    FieldInfo::new("dest_control", 5, 2),
+    FieldInfo::new("src_control", 7, 2),
+    FieldInfo::new("repeat", 9, 1),
+    FieldInfo::new("transfer_32bit", 10, 1),
+    FieldInfo::new("game_pak_drq", 11, 1),
+    FieldInfo::new("start_timing", 12, 2),
+    FieldInfo::new("irq", 14, 1),
+    FieldInfo::new("enable", 15, 1),
+  ];
+
+  /// How the destination address changes.
+  pub const fn dest_control(self) -> DmaAddrControl {
+    match (self.0 >> 5) & 0b11 {
+      0 => DmaAddrControl::Increment,
+      1 => DmaAddrControl::Decrement,
+      2 => DmaAddrControl::Fixed,
+      _ => DmaAddrControl::IncrementReload,
+    }
+  }
+
+  /// Replaces the [`dest_control`](Self::dest_control) value.
+  pub const fn with_dest_control(self, dest_control: DmaAddrControl) -> Self {
+    Self((self.0 & !(0b11 << 5)) | ((dest_control as u16) << 5))
+  }
+
+  /// How the source address changes.
+  pub const fn src_control(self) -> DmaAddrControl {
+    match (self.0 >> 7) & 0b11 {
+      0 => DmaAddrControl::Increment,
+      1 => DmaAddrControl::Decrement,
+      2 => DmaAddrControl::Fixed,
+      _ => DmaAddrControl::IncrementReload,
+    }
+  }
+
+  /// Replaces the [`src_control`](Self::src_control) value.
+  pub const fn with_src_control(self, src_control: DmaAddrControl) -> Self {
+    Self((self.0 & !(0b11 << 7)) | ((src_control as u16) << 7))
+  }
+
+  u16_bool_field!(
+    /// If the transfer repeats at each start timing event.
+    ///
+    /// Has no effect with [`DmaStartTiming::Immediate`].
+    9,
+    repeat,
+    with_repeat
+  );
+  u16_bool_field!(
+    /// If the transfer moves 32-bit units (otherwise 16-bit units).
+    10,
+    transfer_32bit,
+    with_transfer_32bit
+  );
+  u16_bool_field!(
+    /// If the transfer is started by a Game Pak DRQ. DMA 3 only.
+    11,
+    game_pak_drq,
+    with_game_pak_drq
+  );
+
+  /// When the transfer starts.
+  pub const fn start_timing(self) -> DmaStartTiming {
+    match (self.0 >> 12) & 0b11 {
+      0 => DmaStartTiming::Immediate,
+      1 => DmaStartTiming::VBlank,
+      2 => DmaStartTiming::HBlank,
+      _ => DmaStartTiming::Special,
+    }
+  }
+
+  /// Replaces the [`start_timing`](Self::start_timing) value.
+  pub const fn with_start_timing(self, start_timing: DmaStartTiming) -> Self {
+    Self((self.0 & !(0b11 << 12)) | ((start_timing as u16) << 12))
+  }
+
+  u16_bool_field!(
+    /// If an interrupt is sent when the transfer is done.
+    14,
+    irq,
+    with_irq
+  );
+  u16_bool_field!(
+    /// If the unit is enabled.
+    ///
+    /// This is cleared by the hardware when a non-repeating transfer is done.
+    15,
+    enable,
+    with_enable
+  );
+
+  /// The number of bytes in each unit of the transfer.
+  pub const fn unit_bytes(self) -> usize {
+    if self.transfer_32bit() {
+      4
+    } else {
+      2
+    }
+  }
+}
+
+/// The most units that a given DMA unit can transfer at once.
+///
+/// ## Panics
+/// `channel` must be < 4.
+pub const fn dma_max_count(channel: usize) -> u32 {
+  if const_bound_check(channel, DMA_COUNT) == 3 {
+    0x1_0000
+  } else {
+    0x4000
+  }
+}
+
+/// A reason that a [`DmaTransfer`] can't be done.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DmaError {
+  /// The source isn't memory that the DMA unit can read.
+  InvalidSource,
+  /// The destination isn't memory that the DMA unit can write.
+  ///
+  /// Only DMA 3 can write to the `ROM` region (for EEPROM saves).
+  InvalidDestination,
+  /// The source or destination isn't aligned to the unit size.
+  Misaligned,
+  /// The count is 0, or more than the DMA unit can transfer.
+  InvalidCount,
+  /// The source address control is [`DmaAddrControl::IncrementReload`].
+  InvalidSourceControl,
+  /// The Game Pak DRQ bit is set on a unit other than DMA 3.
+  GamePakDrqUnsupported,
+  /// [`DmaStartTiming::Special`] is set on DMA 0.
+  SpecialTimingUnsupported,
+}
+
+/// A complete description of a DMA transfer.
+///
+/// The `count` is the number of units to transfer, not the number of bytes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DmaTransfer {
+  /// The address to read from.
+  pub src: usize,
+  /// The address to write to.
+  pub dst: usize,
+  /// The number of units to transfer.
+  pub count: u32,
+  /// The control value.
+  pub control: DmaControl,
+}
+impl DmaTransfer {
+  /// Checks that the transfer can be done by the given DMA unit.
+  ///
+  /// ## Panics
+  /// `channel` must be < 4.
+  pub const fn validate(&self, channel: usize) -> Result<(), DmaError> {
+    let max_count = dma_max_count(channel);
+    let src_ok = match region_of(self.src) {
+      Some(MemoryRegion::RomWait0)
+      | Some(MemoryRegion::RomWait1)
+      | Some(MemoryRegion::RomWait2)
+        if channel == 0 =>
+      {
+        false
+      }
+      Some(region) => region.info().dma_source,
+      None => false,
+    };
+    let dst_ok = match region_of(self.dst) {
+      // DMA 3 can write to the Game Pak, which is how EEPROM saves are
+      // written (at 0x0D00_0000 and up).
+      Some(MemoryRegion::RomWait0)
+      | Some(MemoryRegion::RomWait1)
+      | Some(MemoryRegion::RomWait2)
+        if channel == 3 =>
+      {
+        true
+      }
+      Some(region) => region.info().dma_destination,
+      None => false,
+    };
+    let unit_mask = self.control.unit_bytes() - 1;
+    if !src_ok {
+      Err(DmaError::InvalidSource)
+    } else if !dst_ok {
+      Err(DmaError::InvalidDestination)
+    } else if (self.src & unit_mask) != 0 || (self.dst & unit_mask) != 0 {
+      Err(DmaError::Misaligned)
+    } else if self.count == 0 || self.count > max_count {
+      Err(DmaError::InvalidCount)
+    } else if matches!(
+      self.control.src_control(),
+      DmaAddrControl::IncrementReload
+    ) {
+      Err(DmaError::InvalidSourceControl)
+    } else if self.control.game_pak_drq() && channel != 3 {
+      Err(DmaError::GamePakDrqUnsupported)
+    } else if matches!(self.control.start_timing(), DmaStartTiming::Special)
+      && channel == 0
+    {
+      Err(DmaError::SpecialTimingUnsupported)
+    } else {
+      Ok(())
+    }
+  }
+
+  /// The value to write to the transfer count register.
+  ///
+  /// The largest possible count doesn't fit in the register's bits, so it
+  /// becomes 0, which the hardware treats as the largest count.
+  pub const fn cnt_l(&self) -> u16 {
+    self.count as u16
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const EEPROM_ADDR: usize = 0x0D00_0000;
+
+  fn transfer(src: usize, dst: usize) -> DmaTransfer {
+    DmaTransfer {
+      src,
+      dst,
+      count: 1,
+      control: DmaControl::new(),
+    }
+  }
+
+  #[test]
+  fn test_rom_destination() {
+    let t = transfer(EWRAM_START_ADDR, EEPROM_ADDR);
+    assert_eq!(t.validate(3), Ok(()));
+    for channel in 0..3 {
+      assert_eq!(t.validate(channel), Err(DmaError::InvalidDestination));
+    }
+  }
+
+  #[test]
+  fn test_rom_source() {
+    let t = transfer(ROM_WAIT0_BASE_ADDR, EWRAM_START_ADDR);
+    assert_eq!(t.validate(0), Err(DmaError::InvalidSource));
+    for channel in 1..4 {
+      assert_eq!(t.validate(channel), Ok(()));
+    }
+  }
+}
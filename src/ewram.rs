@@ -9,9 +9,11 @@
 //!
 //! * **Size:** 256kb
 //! * **Wait states:** 2
-//! * **Bus Size:** 32-bit
+//! * **Bus Size:** 16-bit
 //! * **Read/Write:** 8/16/32
 
+use super::*;
+
 /// Base Address of `EWRAM`
 pub const EWRAM_START_ADDR: usize = 0x0200_0000;
 
@@ -20,3 +22,13 @@ pub const EWRAM_ENTRY_SIZE: usize = 1;
 
 /// There is 256kb of memory in `EWRAM`.
 pub const EWRAM_COUNT: usize = 256 * 1024;
+
+/// Access info for `EWRAM`.
+pub const EWRAM_REGION_INFO: RegionInfo = RegionInfo {
+  bus_width: AccessWidth::Bits16,
+  reads: AccessWidths::ALL,
+  writes: AccessWidths::ALL,
+  wait_states: 2,
+  dma_source: true,
+  dma_destination: true,
+};
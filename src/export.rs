@@ -0,0 +1,676 @@
+//! Exporting the memory map to other formats.
+//!
+//! This module is only available with the `std` feature. The functions here are
+//! intended for use in a build script, so that other parts of a project can use
+//! the same values as this crate without having to copy them by hand.
+
+use super::*;
+use core::fmt::Write;
+use std::{format, string::String, vec::Vec};
+
+macro_rules! named_values {
+  ($($name:ident),* $(,)?) => {
+    &[$((stringify!($name), $name)),*]
+  };
+}
+
+/// Every address and size outside of the IO registers, grouped by module.
+///
+/// The IO registers are all in [`IO_REGISTERS`].
+const MEMORY_MAP: &[(&str, &[(&str, usize)])] = &[
+  ("BIOS", named_values![BIOS_START_ADDR, BIOS_COUNT]),
+  (
+    "EWRAM",
+    named_values![EWRAM_START_ADDR, EWRAM_ENTRY_SIZE, EWRAM_COUNT],
+  ),
+  (
+    "IWRAM",
+    named_values![
+      IWRAM_START_ADDR,
+      IWRAM_ENTRY_SIZE,
+      IWRAM_COUNT,
+      IWRAM_RESERVED,
+      IRQ_HANDLER_ADDR,
+      IRQ_INTR_WAIT_CHECK_FLAG_ADDR,
+      IRQ_INTR_WAIT_CHECK_FLAG_LAST_MIRROR_ADDR,
+      DEFAULT_SP_SVC,
+      DEFAULT_SP_IRQ,
+      DEFAULT_SP_USER,
+    ],
+  ),
+  (
+    "IO",
+    named_values![
+      IO_START_ADDR,
+      IO_COUNT,
+      BG_COUNT,
+      BG_CNT_STRIDE,
+      BG_OFS_STRIDE,
+      BG_AFFINE_STRIDE,
+      DMA_COUNT,
+      DMA_STRIDE,
+      TIMER_COUNT,
+      TIMER_STRIDE,
+    ],
+  ),
+  (
+    "PALRAM",
+    named_values![
+      PALRAM_START_ADDR,
+      PALRAM_COUNT,
+      BG_PALETTE_RAM_ADDR,
+      BG_PALETTE_RAM_ENTRY_SIZE,
+      BG_PALETTE_RAM_COUNT,
+      BACKDROP_COLOR_ADDR,
+      OBJ_PALETTE_RAM_ADDR,
+      OBJ_PALETTE_RAM_ENTRY_SIZE,
+      OBJ_PALETTE_RAM_COUNT,
+    ],
+  ),
+  (
+    "VRAM",
+    named_values![
+      VRAM_BASE_ADDR,
+      VRAM_COUNT,
+      TILE_4BPP_SIZE,
+      TILE_8BPP_SIZE,
+      CHARBLOCK_4BPP_COUNT,
+      CHARBLOCK_8BPP_COUNT,
+      CHARBLOCK_SIZE,
+      CHARBLOCK_BG_BASE_ADDR,
+      CHARBLOCK_BG_COUNT,
+      CHARBLOCK_OBJ_BASE_ADDR,
+      CHARBLOCK_OBJ_COUNT,
+      CHARBLOCK_OBJ_TILE_COUNT,
+      TEXT_SCREENBLOCK_ENTRY_SIZE,
+      TEXT_SCREENBLOCK_ENTRY_COUNT,
+      TEXT_SCREENBLOCK_SIZE,
+      SCREENBLOCK_COUNT,
+      AFFINE_SCREENBLOCK_ENTRY_SIZE,
+      AFFINE_SIZE0_SCREENBLOCK_ENTRY_COUNT,
+      AFFINE_SIZE0_SCREENBLOCK_SIZE,
+      AFFINE_SIZE1_SCREENBLOCK_ENTRY_COUNT,
+      AFFINE_SIZE1_SCREENBLOCK_SIZE,
+      AFFINE_SIZE2_SCREENBLOCK_ENTRY_COUNT,
+      AFFINE_SIZE2_SCREENBLOCK_SIZE,
+      AFFINE_SIZE3_SCREENBLOCK_ENTRY_COUNT,
+      AFFINE_SIZE3_SCREENBLOCK_SIZE,
+      VRAM_FRAME0_BASE_ADDR,
+      VRAM_MODE4_FRAME1_BASE_ADDR,
+    ],
+  ),
+  (
+    "OAM",
+    named_values![
+      OAM_START_ADDR,
+      OAM_COUNT,
+      OBJ_ATTR0_BASE_ADDR,
+      OBJ_ATTR1_BASE_ADDR,
+      OBJ_ATTR2_BASE_ADDR,
+      OBJ_ATTR_STRIDE,
+      OBJ_ATTR_COUNT,
+      OBJ_AFFINE_PA_BASE_ADDR,
+      OBJ_AFFINE_PB_BASE_ADDR,
+      OBJ_AFFINE_PC_BASE_ADDR,
+      OBJ_AFFINE_PD_BASE_ADDR,
+      OBJ_AFFINE_STRIDE,
+      OBJ_AFFINE_COUNT,
+    ],
+  ),
+  (
+    "ROM",
+    named_values![
+      ROM_WAIT0_BASE_ADDR,
+      ROM_WAIT1_BASE_ADDR,
+      ROM_WAIT2_BASE_ADDR,
+      ROM_MAX_COUNT,
+    ],
+  ),
+  (
+    "SRAM",
+    named_values![SRAM_BASE_ADDR, SRAM_ENTRY_SIZE, SRAM_COUNT],
+  ),
+];
+
+/// C macro versions of the indexing functions.
+///
+/// Unlike the Rust functions, these don't bounds check the index values.
+///
+/// The assembler macros are made from these as well, so each body can only
+/// use the arguments, the values in [`MEMORY_MAP`] and [`IO_REGISTERS`], and
+/// operators that both C and GNU `as` understand.
+const C_INDEX_MACROS: &[&str] = &[
+  "#define index_obj_attr(i) (OBJ_ATTR0_BASE_ADDR + (OBJ_ATTR_STRIDE * (i)))",
+  "#define index_obj_affine_param(i) (OBJ_AFFINE_PA_BASE_ADDR + (OBJ_AFFINE_STRIDE * (i)))",
+  "#define index_bg_palette_4bpp(p, i) (BG_PALETTE_RAM_ADDR + (BG_PALETTE_RAM_ENTRY_SIZE * (((p) << 4) + (i))))",
+  "#define index_bg_palette_8bpp(i) (BG_PALETTE_RAM_ADDR + (BG_PALETTE_RAM_ENTRY_SIZE * (i)))",
+  "#define index_obj_palette_4bpp(p, i) (OBJ_PALETTE_RAM_ADDR + (OBJ_PALETTE_RAM_ENTRY_SIZE * (((p) << 4) + (i))))",
+  "#define index_obj_palette_8bpp(i) (OBJ_PALETTE_RAM_ADDR + (OBJ_PALETTE_RAM_ENTRY_SIZE * (i)))",
+  "#define index_bg_charblock(i) (CHARBLOCK_BG_BASE_ADDR + (CHARBLOCK_SIZE * (i)))",
+  "#define index_tile_4bpp(charblock, i) ((charblock) + (TILE_4BPP_SIZE * (i)))",
+  "#define index_tile_8bpp(charblock, i) ((charblock) + (TILE_8BPP_SIZE * (i)))",
+  "#define index_obj_tile(i) (CHARBLOCK_OBJ_BASE_ADDR + (TILE_4BPP_SIZE * (i)))",
+  "#define index_screenblock(i) (VRAM_BASE_ADDR + (TEXT_SCREENBLOCK_SIZE * (i)))",
+  "#define index_text_screen_entry(screenblock, bg_size, x, y) (VRAM_BASE_ADDR + (TEXT_SCREENBLOCK_SIZE * ((screenblock) + ((x) >> 5) + (((y) >> 5) << ((bg_size) & 1)))) + (TEXT_SCREENBLOCK_ENTRY_SIZE * ((((y) & 31) << 5) + ((x) & 31))))",
+  "#define bg_cnt(i) (BG0CNT_ADDR + (BG_CNT_STRIDE * (i)))",
+  "#define bg_hofs(i) (BG0HOFS_ADDR + (BG_OFS_STRIDE * (i)))",
+  "#define bg_vofs(i) (BG0VOFS_ADDR + (BG_OFS_STRIDE * (i)))",
+  "#define bg_affine_addr(bg) (BG2PA_ADDR + (BG_AFFINE_STRIDE * ((bg) - 2)))",
+  "#define dma_sad(i) (DMA0SAD_ADDR + (DMA_STRIDE * (i)))",
+  "#define dma_dad(i) (DMA0DAD_ADDR + (DMA_STRIDE * (i)))",
+  "#define dma_cnt_l(i) (DMA0CNT_L_ADDR + (DMA_STRIDE * (i)))",
+  "#define dma_cnt_h(i) (DMA0CNT_H_ADDR + (DMA_STRIDE * (i)))",
+  "#define timer_cnt_l(i) (TM0CNT_L_ADDR + (TIMER_STRIDE * (i)))",
+  "#define timer_cnt_h(i) (TM0CNT_H_ADDR + (TIMER_STRIDE * (i)))",
+];
+
+/// Converts one of the [`C_INDEX_MACROS`] into a GNU assembler macro.
+///
+/// The macro takes the name of a symbol to define as its first argument,
+/// followed by the arguments of the C macro, so `index_obj_attr(i)` becomes
+/// `index_obj_attr out, i`.
+fn asm_index_macro(c_macro: &str) -> String {
+  let rest = c_macro.trim_start_matches("#define ");
+  let (name, rest) = rest.split_at(rest.find('(').unwrap());
+  let (args, body) = rest[1..].split_at(rest.find(')').unwrap() - 1);
+  let args: Vec<&str> = args.split(',').map(str::trim).collect();
+  let mut body = String::from(body[1..].trim());
+  for arg in args.iter() {
+    body = body.replace(&format!("({})", arg), &format!("(\\{})", arg));
+  }
+  format!(
+    ".macro {} out, {}\n  .equ \\out, {}\n.endm",
+    name,
+    args.join(", "),
+    body
+  )
+}
+
+/// Readable name of an access mode, for comments.
+fn access_name(access: RegisterAccess) -> &'static str {
+  match access {
+    RegisterAccess::ReadOnly => "read-only",
+    RegisterAccess::WriteOnly => "write-only",
+    RegisterAccess::ReadWrite => "read/write",
+  }
+}
+
+/// The kind of program that a linker script is for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LinkTarget {
+  /// A normal cartridge program.
+  ///
+  /// Code and read-only data are placed in `ROM`, and initialized data is
+  /// copied out of `ROM` to `IWRAM` and `EWRAM` at startup.
+  Cartridge,
+  /// A multiboot program.
+  ///
+  /// The entire program is sent over the link cable into `EWRAM`, so code and
+  /// read-only data are placed in `EWRAM`, and initialized data is copied out
+  /// of `EWRAM` to `IWRAM` at startup.
+  Multiboot,
+}
+
+/// Generates a GNU `ld` linker script.
+///
+/// The script uses `__start` as the entry point, and defines the following
+/// symbols for the startup code to use:
+/// * `__sp_usr`, `__sp_irq`, `__sp_svc`: The default stack pointers.
+/// * `__iwram_start`, `__iwram_end`, `__iwram_position_in_rom`: Where the
+///   `.data` and `.iwram` sections go, and where to copy them from.
+/// * `__iwram_word_copy_count`: The number of words to copy into `IWRAM`.
+/// * `__ewram_start`, `__ewram_end`, `__ewram_position_in_rom`: Same as above,
+///   for the `.ewram` section.
+/// * `__ewram_word_copy_count`: The number of words to copy into `EWRAM`.
+/// * `__bss_start`, `__bss_end`, `__bss_word_clear_count`: The span of `IWRAM`
+///   to zero at startup.
+///
+/// With [`LinkTarget::Multiboot`], the "rom" positions refer to the load
+/// position within `EWRAM` instead.
+pub fn linker_script(target: LinkTarget) -> String {
+  let load = match target {
+    LinkTarget::Cartridge => "rom",
+    LinkTarget::Multiboot => "ewram",
+  };
+  let mut s = String::new();
+  // Writing to a `String` can't fail.
+  let _ = write!(
+    s,
+    "\
+/* Generated by the `gba-addresses` crate. */
+
+ENTRY(__start)
+
+MEMORY {{
+  ewram (w!x) : ORIGIN = {ewram:#010X}, LENGTH = {ewram_len:#X}
+  iwram (w!x) : ORIGIN = {iwram:#010X}, LENGTH = {iwram_len:#X}
+  rom (rx)    : ORIGIN = {rom:#010X}, LENGTH = {rom_len:#X}
+}}
+
+__sp_usr = {sp_usr:#010X};
+__sp_irq = {sp_irq:#010X};
+__sp_svc = {sp_svc:#010X};
+
+SECTIONS {{
+  .text : {{
+    KEEP(*(.text.gba_rom_header));
+    *(.text .text.*);
+    . = ALIGN(4);
+  }} >{load}
+
+  .rodata : {{
+    *(.rodata .rodata.*);
+    . = ALIGN(4);
+  }} >{load}
+
+  . = ALIGN(4);
+  __iwram_position_in_rom = .;
+  .data : {{
+    __iwram_start = ABSOLUTE(.);
+    *(.data .data.*);
+    *(.iwram .iwram.*);
+    . = ALIGN(4);
+    __iwram_end = ABSOLUTE(.);
+  }} >iwram AT>{load}
+  __iwram_word_copy_count = (__iwram_end - __iwram_start) / 4;
+
+  .bss : {{
+    __bss_start = ABSOLUTE(.);
+    *(.bss .bss.*);
+    . = ALIGN(4);
+    __bss_end = ABSOLUTE(.);
+  }} >iwram
+  __bss_word_clear_count = (__bss_end - __bss_start) / 4;
+
+  __ewram_position_in_rom = __iwram_position_in_rom + (__iwram_end - __iwram_start);
+  .ewram : {{
+    __ewram_start = ABSOLUTE(.);
+    *(.ewram .ewram.*);
+    . = ALIGN(4);
+    __ewram_end = ABSOLUTE(.);
+  }} >ewram AT>{load}
+  __ewram_word_copy_count = (__ewram_end - __ewram_start) / 4;
+
+  /* DWARF debug sections, from the binutils default script. */
+  .debug_info     0 : {{ *(.debug_info .gnu.linkonce.wi.*) }}
+  .debug_abbrev   0 : {{ *(.debug_abbrev) }}
+  .debug_line     0 : {{ *(.debug_line .debug_line.* .debug_line_end) }}
+  .debug_frame    0 : {{ *(.debug_frame) }}
+  .debug_str      0 : {{ *(.debug_str) }}
+  .debug_loc      0 : {{ *(.debug_loc) }}
+  .debug_macinfo  0 : {{ *(.debug_macinfo) }}
+  .debug_aranges  0 : {{ *(.debug_aranges) }}
+  .debug_ranges   0 : {{ *(.debug_ranges) }}
+  .debug_pubnames 0 : {{ *(.debug_pubnames) }}
+  .debug_pubtypes 0 : {{ *(.debug_pubtypes) }}
+
+  /DISCARD/ : {{
+    *(.ARM.exidx .ARM.exidx.*);
+  }}
+}}
+",
+    ewram = EWRAM_START_ADDR,
+    ewram_len = EWRAM_COUNT,
+    iwram = IWRAM_START_ADDR,
+    iwram_len = IWRAM_RESERVED - IWRAM_START_ADDR,
+    rom = ROM_WAIT0_BASE_ADDR,
+    rom_len = ROM_MAX_COUNT,
+    sp_usr = DEFAULT_SP_USER,
+    sp_irq = DEFAULT_SP_IRQ,
+    sp_svc = DEFAULT_SP_SVC,
+    load = load,
+  );
+  s
+}
+
+/// Generates a C header file.
+///
+/// The header has a `#define` for every address and size in this crate, as
+/// well as function-like macros for the indexing functions (such as
+/// `index_obj_attr(i)`). The macros use the same names as the Rust functions,
+/// but they don't do any bounds checking.
+pub fn c_header() -> String {
+  let mut s = String::new();
+  // Writing to a `String` can't fail.
+  let _ = writeln!(s, "/* Generated by the `gba-addresses` crate. */");
+  let _ = writeln!(s);
+  let _ = writeln!(s, "#ifndef GBA_ADDRESSES_H");
+  let _ = writeln!(s, "#define GBA_ADDRESSES_H");
+  for (module, values) in MEMORY_MAP {
+    let _ = writeln!(s);
+    let _ = writeln!(s, "/* {} */", module);
+    for (name, value) in values.iter() {
+      let _ = writeln!(s, "#define {} {:#010X}", name, value);
+    }
+  }
+  let mut region = None;
+  for reg in IO_REGISTERS {
+    if region != Some(reg.region) {
+      region = Some(reg.region);
+      let _ = writeln!(s);
+      let _ = writeln!(s, "/* IO: {:?} */", reg.region);
+    }
+    let _ = writeln!(
+      s,
+      "#define {} {:#010X} /* {}, {} byte(s) */",
+      reg.name,
+      reg.addr,
+      access_name(reg.access),
+      reg.size
+    );
+  }
+  let _ = writeln!(s);
+  let _ = writeln!(s, "/* Indexing (not bounds checked) */");
+  for line in C_INDEX_MACROS {
+    let _ = writeln!(s, "{}", line);
+  }
+  let _ = writeln!(s);
+  let _ = writeln!(s, "#endif /* GBA_ADDRESSES_H */");
+  s
+}
+
+/// Generates a GNU assembler include file.
+///
+/// The file has an `.equ NAME, value` line for every address and size in this
+/// crate, as well as a macro for each of the indexing functions. Each macro
+/// defines the symbol named by its first argument, so `index_obj_attr attr3,
+/// 3` is the same as `.equ attr3, index_obj_attr(3)`. Like the C macros, these
+/// don't do any bounds checking.
+///
+/// If `io_offsets` is set, every IO register also gets a `_OFFSET` version
+/// (eg: `IME_OFFSET` for `IME_ADDR`), which is the offset from
+/// [`IO_START_ADDR`]. This allows `ldr rX, [rIO, #off]` style addressing once a
+/// register holds the base address. This also includes
+/// `IRQ_INTR_WAIT_CHECK_FLAG_LAST_MIRROR_OFFSET`, which is negative.
+pub fn asm_include(io_offsets: bool) -> String {
+  let mut s = String::new();
+  // Writing to a `String` can't fail.
+  let _ = writeln!(s, "/* Generated by the `gba-addresses` crate. */");
+  for (module, values) in MEMORY_MAP {
+    let _ = writeln!(s);
+    let _ = writeln!(s, "/* {} */", module);
+    for (name, value) in values.iter() {
+      let _ = writeln!(s, ".equ {}, {:#010X}", name, value);
+    }
+  }
+  let mut region = None;
+  for reg in IO_REGISTERS {
+    if region != Some(reg.region) {
+      region = Some(reg.region);
+      let _ = writeln!(s);
+      let _ = writeln!(s, "/* IO: {:?} */", reg.region);
+    }
+    let _ = writeln!(s, ".equ {}, {:#010X}", reg.name, reg.addr);
+  }
+  let _ = writeln!(s);
+  let _ = writeln!(s, "/* Indexing (not bounds checked) */");
+  for line in C_INDEX_MACROS {
+    let _ = writeln!(s, "{}", asm_index_macro(line));
+  }
+  if io_offsets {
+    let _ = writeln!(s);
+    let _ = writeln!(s, "/* IO offsets from IO_START_ADDR */");
+    for reg in IO_REGISTERS {
+      let base = reg.name.strip_suffix("_ADDR").unwrap_or(reg.name);
+      let _ =
+        writeln!(s, ".equ {}_OFFSET, {:#05X}", base, reg.addr - IO_START_ADDR);
+    }
+    let _ = writeln!(
+      s,
+      ".equ IRQ_INTR_WAIT_CHECK_FLAG_LAST_MIRROR_OFFSET, -{:#X}",
+      IO_START_ADDR - IRQ_INTR_WAIT_CHECK_FLAG_LAST_MIRROR_ADDR
+    );
+  }
+  s
+}
+
+/// The fields of a register, if it has a value type.
+fn register_fields(name: &str) -> &'static [FieldInfo] {
+  match name {
+    "DISPCNT_ADDR" => DisplayControl::FIELDS,
+    "DISPSTAT_ADDR" => DisplayStatus::FIELDS,
+    "BG0CNT_ADDR" | "BG1CNT_ADDR" | "BG2CNT_ADDR" | "BG3CNT_ADDR" => {
+      BackgroundControl::FIELDS
+    }
+    "DMA0CNT_H_ADDR" | "DMA1CNT_H_ADDR" | "DMA2CNT_H_ADDR"
+    | "DMA3CNT_H_ADDR" => DmaControl::FIELDS,
+    "TM0CNT_H_ADDR" | "TM1CNT_H_ADDR" | "TM2CNT_H_ADDR" | "TM3CNT_H_ADDR" => {
+      TimerControl::FIELDS
+    }
+    "KEYINPUT_ADDR" => Keys::KEYINPUT_FIELDS,
+    "KEYCNT_ADDR" => KeyInterruptControl::FIELDS,
+    "IE_ADDR" | "IF_ADDR" => IrqBits::FIELDS,
+    "WAITCNT_ADDR" => WaitControl::FIELDS,
+    _ => &[],
+  }
+}
+
+/// The `(start, end)` address span of each group of registers that a
+/// peripheral is declared in.
+fn address_blocks(peripheral: IoPeripheral) -> Vec<(usize, usize)> {
+  let mut blocks: Vec<(usize, usize)> = Vec::new();
+  let mut previous = None;
+  for reg in IO_REGISTERS {
+    if reg.region == peripheral {
+      let (start, end) = (reg.addr, reg.addr + reg.size);
+      match blocks.last_mut() {
+        Some(block) if previous == Some(peripheral) => {
+          block.0 = block.0.min(start);
+          block.1 = block.1.max(end);
+        }
+        _ => blocks.push((start, end)),
+      }
+    }
+    previous = Some(reg.region);
+  }
+  blocks
+}
+
+/// Generates a CMSIS-SVD description of the IO registers.
+///
+/// Each [`IoPeripheral`] becomes a peripheral, and each register within it is
+/// listed with its access and size. Registers that have a value type in this
+/// crate (such as [`DisplayControl`]) also list their fields.
+///
+/// * Register names drop the `_ADDR` suffix.
+/// * Registers that share an address with an earlier register are marked as
+///   an `alternateRegister` of that register.
+/// * Registers larger than 32 bits are given as an array of 32-bit registers.
+/// * A peripheral with registers declared in more than one place (such as
+///   [`IoPeripheral::Interrupt`]) gets an address block for each place.
+pub fn svd() -> String {
+  let mut peripherals: Vec<IoPeripheral> = Vec::new();
+  for reg in IO_REGISTERS {
+    if !peripherals.contains(&reg.region) {
+      peripherals.push(reg.region);
+    }
+  }
+
+  let mut s = String::new();
+  // Writing to a `String` can't fail.
+  let _ = write!(
+    s,
+    "\
+<?xml version=\"1.0\" encoding=\"utf-8\"?>
+<!-- Generated by the `gba-addresses` crate. -->
+<device schemaVersion=\"1.3\" xmlns:xs=\"http://www.w3.org/2001/XMLSchema-instance\" xs:noNamespaceSchemaLocation=\"CMSIS-SVD.xsd\">
+  <vendor>Nintendo</vendor>
+  <name>GBA</name>
+  <version>{version}</version>
+  <description>Game Boy Advance IO registers</description>
+  <addressUnitBits>8</addressUnitBits>
+  <width>32</width>
+  <size>16</size>
+  <access>read-write</access>
+  <peripherals>
+",
+    version = env!("CARGO_PKG_VERSION"),
+  );
+  for peripheral in peripherals {
+    let regs = || IO_REGISTERS.iter().filter(move |r| r.region == peripheral);
+    let base = regs().map(|r| r.addr).min().unwrap_or(IO_START_ADDR);
+    let _ = writeln!(s, "    <peripheral>");
+    let _ = writeln!(
+      s,
+      "      <name>{}</name>",
+      format!("{:?}", peripheral).to_uppercase()
+    );
+    let _ = writeln!(s, "      <baseAddress>{:#010X}</baseAddress>", base);
+    // A peripheral's registers can be declared in more than one group (eg:
+    // `WAITCNT_ADDR` sits between the interrupt registers), so each group gets
+    // its own address block, and no block overlaps another peripheral.
+    for (start, end) in address_blocks(peripheral) {
+      let _ = writeln!(s, "      <addressBlock>");
+      let _ = writeln!(s, "        <offset>{:#X}</offset>", start - base);
+      let _ = writeln!(s, "        <size>{:#X}</size>", end - start);
+      let _ = writeln!(s, "        <usage>registers</usage>");
+      let _ = writeln!(s, "      </addressBlock>");
+    }
+    let _ = writeln!(s, "      <registers>");
+    for reg in regs() {
+      let name = reg.name.strip_suffix("_ADDR").unwrap_or(reg.name);
+      let access = match reg.access {
+        RegisterAccess::ReadOnly => "read-only",
+        RegisterAccess::WriteOnly => "write-only",
+        RegisterAccess::ReadWrite => "read-write",
+      };
+      let _ = writeln!(s, "        <register>");
+      if reg.size > 4 {
+        let _ = writeln!(s, "          <dim>{}</dim>", reg.size / 4);
+        let _ = writeln!(s, "          <dimIncrement>0x4</dimIncrement>");
+        let _ = writeln!(s, "          <name>{}[%s]</name>", name);
+      } else {
+        let _ = writeln!(s, "          <name>{}</name>", name);
+      }
+      let alternate = IO_REGISTERS
+        .iter()
+        .take_while(|other| other.name != reg.name)
+        .find(|other| other.addr == reg.addr);
+      if let Some(other) = alternate {
+        let other_name = other.name.strip_suffix("_ADDR").unwrap_or(other.name);
+        let _ = writeln!(
+          s,
+          "          <alternateRegister>{}</alternateRegister>",
+          other_name
+        );
+      }
+      let _ = writeln!(
+        s,
+        "          <addressOffset>{:#X}</addressOffset>",
+        reg.addr - base
+      );
+      let _ = writeln!(s, "          <size>{}</size>", reg.size.min(4) * 8);
+      let _ = writeln!(s, "          <access>{}</access>", access);
+      let fields = register_fields(reg.name);
+      if !fields.is_empty() {
+        let _ = writeln!(s, "          <fields>");
+        for field in fields {
+          let _ = writeln!(s, "            <field>");
+          let _ = writeln!(s, "              <name>{}</name>", field.name);
+          let _ =
+            writeln!(s, "              <bitOffset>{}</bitOffset>", field.lsb);
+          let _ =
+            writeln!(s, "              <bitWidth>{}</bitWidth>", field.width);
+          let _ = writeln!(s, "            </field>");
+        }
+        let _ = writeln!(s, "          </fields>");
+      }
+      let _ = writeln!(s, "        </register>");
+    }
+    let _ = writeln!(s, "      </registers>");
+    let _ = writeln!(s, "    </peripheral>");
+  }
+  let _ = writeln!(s, "  </peripherals>");
+  let _ = writeln!(s, "</device>");
+  s
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// The names of every top level `pub const` `usize` value and `pub const
+  /// fn` that returns a `usize`, in every module of the crate.
+  ///
+  /// The IO registers aren't included, since they're declared in a macro.
+  fn exported_items() -> Vec<(String, bool)> {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+    let mut items = Vec::new();
+    for entry in std::fs::read_dir(dir).unwrap() {
+      let text = std::fs::read_to_string(entry.unwrap().path()).unwrap();
+      for (i, line) in text.lines().enumerate() {
+        if let Some(f) = line.strip_prefix("pub const fn ") {
+          let name = &f[..f.find('(').unwrap()];
+          let rest = &text[text.lines().take(i).map(|l| l.len() + 1).sum()..];
+          let signature = &rest[..rest.find('{').unwrap()];
+          if signature.contains("-> usize") {
+            items.push((String::from(name), true));
+          }
+        } else if let Some(c) = line.strip_prefix("pub const ") {
+          if let Some(name) = c
+            .strip_suffix(": usize =")
+            .or_else(|| c.find(": usize = ").map(|end| &c[..end]))
+          {
+            items.push((String::from(name), false));
+          }
+        }
+      }
+    }
+    items
+  }
+
+  #[test]
+  fn test_c_header_has_everything() {
+    let header = c_header();
+    for (name, is_fn) in exported_items() {
+      let define = if is_fn {
+        format!("#define {}(", name)
+      } else {
+        format!("#define {} ", name)
+      };
+      assert!(header.contains(&define), "{} is missing", name);
+    }
+  }
+
+  #[test]
+  fn test_asm_include_has_everything() {
+    let include = asm_include(false);
+    for (name, is_fn) in exported_items() {
+      let def = if is_fn {
+        format!(".macro {} out, ", name)
+      } else {
+        format!(".equ {}, ", name)
+      };
+      assert!(include.contains(&def), "{} is missing", name);
+    }
+  }
+
+  #[test]
+  fn test_asm_index_macro() {
+    assert_eq!(
+      asm_index_macro("#define index_tile_4bpp(charblock, i) ((charblock) + (TILE_4BPP_SIZE * (i)))"),
+      ".macro index_tile_4bpp out, charblock, i\n  .equ \\out, ((\\charblock) + (TILE_4BPP_SIZE * (\\i)))\n.endm"
+    );
+  }
+
+  #[test]
+  fn test_svd_address_blocks_dont_overlap() {
+    let mut blocks = Vec::new();
+    for reg in IO_REGISTERS {
+      for block in address_blocks(reg.region) {
+        if !blocks.contains(&block) {
+          blocks.push(block);
+        }
+      }
+    }
+    blocks.sort();
+    for pair in blocks.windows(2) {
+      assert!(
+        pair[0].1 <= pair[1].0,
+        "{:X?} overlaps {:X?}",
+        pair[0],
+        pair[1]
+      );
+    }
+  }
+}
@@ -0,0 +1,220 @@
+//! Fixed point number types for the hardware's register formats.
+//!
+//! * [`I8F8`]: Signed 8.8, used by the affine parameters of objects and of
+//!   BG2/BG3.
+//! * [`I20F8`]: Signed 20.8, used by the 28-bit BG2/BG3 reference point
+//!   registers.
+//! * [`U1F4`]: Unsigned 1.4, used by the blend coefficients `EVA`, `EVB`, and
+//!   `EVY`. Only 0 through 1.0 are used, larger values act as 1.0.
+//!
+//! All arithmetic saturates at the limits of the type rather than wrapping,
+//! and the `to_register_bits` functions give exactly the bits that the
+//! register expects.
+
+// The crate items are only used by doc links.
+#[allow(unused_imports)]
+use super::*;
+
+/// Declares the functions that all of the fixed point types share.
+macro_rules! fixed_common {
+  ($inner:ty, $frac:literal, $min:expr, $max:expr) => {
+    /// The number of fractional bits.
+    pub const FRACTION_BITS: u32 = $frac;
+    /// Zero.
+    pub const ZERO: Self = Self(0);
+    /// One.
+    pub const ONE: Self = Self(1 << $frac);
+    /// The smallest value.
+    pub const MIN: Self = Self($min);
+    /// The largest value.
+    pub const MAX: Self = Self($max);
+
+    /// Clamps a wide value into the range of the type.
+    const fn saturate(v: i64) -> Self {
+      if v < $min as i64 {
+        Self::MIN
+      } else if v > $max as i64 {
+        Self::MAX
+      } else {
+        Self(v as $inner)
+      }
+    }
+
+    /// Makes a value from raw bits, saturating to the range of the type.
+    pub const fn from_bits(bits: $inner) -> Self {
+      Self::saturate(bits as i64)
+    }
+
+    /// The raw bits of the value.
+    pub const fn to_bits(self) -> $inner {
+      self.0
+    }
+
+    /// Makes a value from an integer, saturating to the range of the type.
+    pub const fn from_int(i: i32) -> Self {
+      Self::saturate((i as i64) << $frac)
+    }
+
+    /// The integer part of the value, rounded towards negative infinity.
+    pub const fn to_int(self) -> i32 {
+      ((self.0 as i64) >> $frac) as i32
+    }
+
+    /// Makes a value from an `f32`, rounding to the nearest value and
+    /// saturating to the range of the type.
+    ///
+    /// `NaN` becomes zero.
+    pub fn from_f32(f: f32) -> Self {
+      let scaled = f * (1_i64 << $frac) as f32;
+      let rounded = if scaled < 0.0 {
+        scaled - 0.5
+      } else {
+        scaled + 0.5
+      };
+      Self::saturate(rounded as i64)
+    }
+
+    /// Converts the value to an `f32`.
+    pub fn to_f32(self) -> f32 {
+      self.0 as f32 / (1_i64 << $frac) as f32
+    }
+
+    /// Adds two values, saturating at the limits of the type.
+    pub const fn saturating_add(self, other: Self) -> Self {
+      Self::saturate(self.0 as i64 + other.0 as i64)
+    }
+
+    /// Subtracts two values, saturating at the limits of the type.
+    pub const fn saturating_sub(self, other: Self) -> Self {
+      Self::saturate(self.0 as i64 - other.0 as i64)
+    }
+
+    /// Multiplies two values, saturating at the limits of the type.
+    ///
+    /// The result is rounded towards negative infinity, like an arithmetic
+    /// shift.
+    pub const fn saturating_mul(self, other: Self) -> Self {
+      Self::saturate((self.0 as i64 * other.0 as i64) >> $frac)
+    }
+
+    /// Divides two values, saturating at the limits of the type.
+    ///
+    /// Returns `None` if `other` is zero.
+    pub const fn checked_div(self, other: Self) -> Option<Self> {
+      if other.0 == 0 {
+        None
+      } else {
+        Some(Self::saturate(((self.0 as i64) << $frac) / other.0 as i64))
+      }
+    }
+  };
+}
+
+/// A signed 8.8 fixed point value.
+///
+/// This is the format of [`BG2PA_ADDR`] through [`BG2PD_ADDR`] (and the BG3
+/// equivalents), and of the object affine parameters in `OAM`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct I8F8(i16);
+impl I8F8 {
+  fixed_common!(i16, 8, i16::MIN, i16::MAX);
+
+  /// Converts a 16.16 fixed point value, rounding to the nearest value and
+  /// saturating to the range of the type.
+  pub const fn from_16_16(v: i32) -> Self {
+    Self::saturate((v as i64 + 0x80) >> 8)
+  }
+
+  /// Converts into a 16.16 fixed point value.
+  pub const fn to_16_16(self) -> i32 {
+    (self.0 as i32) << 8
+  }
+
+  /// Makes a value from the bits of a register.
+  pub const fn from_register_bits(bits: u16) -> Self {
+    Self(bits as i16)
+  }
+
+  /// The bits to write to a register.
+  pub const fn to_register_bits(self) -> u16 {
+    self.0 as u16
+  }
+}
+
+/// A signed 20.8 fixed point value, which is 28 bits in total.
+///
+/// This is the format of [`BG2X_ADDR`] and [`BG2Y_ADDR`] (and the BG3
+/// equivalents).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct I20F8(i32);
+impl I20F8 {
+  fixed_common!(i32, 8, -(1 << 27), (1 << 27) - 1);
+
+  /// Converts from an 8.8 value. This never loses precision.
+  pub const fn from_i8f8(v: I8F8) -> Self {
+    Self(v.0 as i32)
+  }
+
+  /// Makes a value from the bits of a register.
+  ///
+  /// Only the lowest 28 bits are used, and bit 27 is the sign bit.
+  pub const fn from_register_bits(bits: u32) -> Self {
+    Self(((bits << 4) as i32) >> 4)
+  }
+
+  /// The bits to write to a register.
+  ///
+  /// The upper 4 bits are always 0.
+  pub const fn to_register_bits(self) -> u32 {
+    (self.0 as u32) & 0x0FFF_FFFF
+  }
+}
+
+/// An unsigned 1.4 fixed point value, from 0 through 1.0.
+///
+/// This is the format of the blend coefficients in [`BLDALPHA_A_ADDR`],
+/// [`BLDALPHA_B_ADDR`], and [`BLDY_ADDR`]. The hardware treats any value above
+/// 1.0 as 1.0, so this type doesn't allow them at all.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct U1F4(u8);
+impl U1F4 {
+  fixed_common!(u8, 4, 0, 16);
+
+  /// Makes a value from the bits of a register.
+  ///
+  /// Only the lowest 5 bits are used, and values above 1.0 become 1.0.
+  pub const fn from_register_bits(bits: u8) -> Self {
+    Self::saturate((bits & 0x1F) as i64)
+  }
+
+  /// The bits to write to a register.
+  pub const fn to_register_bits(self) -> u8 {
+    self.0
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_f32() {
+    assert_eq!(I8F8::from_f32(1.5).to_bits(), 0x180);
+    assert_eq!(I8F8::from_f32(-1.5).to_bits(), -0x180);
+    assert_eq!(I8F8::from_f32(1000.0), I8F8::MAX);
+    assert_eq!(I8F8::from_f32(f32::NAN), I8F8::ZERO);
+    assert_eq!(I8F8::from_bits(-0x80).to_f32(), -0.5);
+    assert_eq!(U1F4::from_f32(2.0), U1F4::ONE);
+  }
+
+  #[test]
+  fn test_register_bits() {
+    assert_eq!(I20F8::from_register_bits(0x0FFF_FF00), I20F8::from_int(-1));
+    assert_eq!(I20F8::from_int(-1).to_register_bits(), 0x0FFF_FF00);
+    assert_eq!(I8F8::from_register_bits(0xFF00), I8F8::from_int(-1));
+    assert_eq!(U1F4::from_register_bits(0x1F), U1F4::ONE);
+  }
+}
@@ -12,722 +12,780 @@
 //! that some documents do sometimes use alternate names for some of the IO
 //! registers (particularly the sound registers).
 //!
+//! Every register is also listed in [`IO_REGISTERS`], along with its access
+//! mode and size, for tools that need that info as data.
+//!
 //! [1]: https://problemkaputt.de/gbatek.htm#gbaiomap
 //!
 //! * **Wait states:** 0
 //! * **Bus Size:** 32-bit
 //! * **Read/Write:** 8/16/32
 
-/// Display Control
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const DISPCNT_ADDR: usize = 0x0400_0000;
-
-/// Display Status
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const DISPSTAT_ADDR: usize = 0x0400_0004;
-
-/// Vertical Counter
-///
-/// * **Access:** read-only
-/// * **Size:** 1
-pub const VCOUNT_ADDR: usize = 0x0400_0006;
-
-// // // // //
-// BG Control
-// // // // //
-
-/// BG0 Control (video modes 0 or 1)
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const BG0CNT_ADDR: usize = 0x0400_0008;
-
-/// BG1 Control (video modes 0 or 1)
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const BG1CNT_ADDR: usize = 0x0400_000A;
-
-/// BG2 Control (video modes 0, 1, or 2)
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const BG2CNT_ADDR: usize = 0x0400_000C;
-
-/// BG3 Control (video modes 0 or 2)
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const BG3CNT_ADDR: usize = 0x0400_000E;
-
-// // // // //
-// Text Offsets
-// // // // //
-
-/// BG0 Horizontal Offset (video modes 0 or 1)
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const BG0HOFS_ADDR: usize = 0x0400_0010;
-
-/// BG0 Vertical Offset (video modes 0 or 1)
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const BG0VOFS_ADDR: usize = 0x0400_0012;
-
-/// BG1 Horizontal Offset (video modes 0 or 1)
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const BG1HOFS_ADDR: usize = 0x0400_0014;
-
-/// BG1 Vertical Offset (video modes 0 or 1)
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const BG1VOFS_ADDR: usize = 0x0400_0016;
-
-/// BG2 Horizontal Offset (video mode 0)
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const BG2HOFS_ADDR: usize = 0x0400_0018;
-
-/// BG1 Vertical Offset (video mode 0)
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const BG2VOFS_ADDR: usize = 0x0400_001A;
-
-/// BG3 Horizontal Offset (video mode 0)
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const BG3HOFS_ADDR: usize = 0x0400_001C;
-
-/// BG3 Vertical Offset (video mode 0)
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const BG3VOFS_ADDR: usize = 0x0400_001E;
-
-// // // // //
-// Affine Parameters
-// // // // //
-
-/// BG2 Affine Param A (video mode 1 and 2)
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const BG2PA_ADDR: usize = 0x0400_0020;
-
-/// BG2 Affine Param B (video mode 1 and 2)
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const BG2PB_ADDR: usize = 0x0400_0022;
-
-/// BG2 Affine Param C (video mode 1 and 2)
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const BG2PC_ADDR: usize = 0x0400_0024;
-
-/// BG2 Affine Param D (video mode 1 and 2)
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const BG2PD_ADDR: usize = 0x0400_0026;
-
-/// BG2 X reference point (video mode 1, 2, 3, 4, and 5)
-///
-/// * **Access:** write-only
-/// * **Size:** 4
-pub const BG2X_ADDR: usize = 0x0400_0028;
-
-/// BG2 Y reference point (video mode 1, 2, 3, 4, and 5)
-///
-/// * **Access:** write-only
-/// * **Size:** 4
-pub const BG2Y_ADDR: usize = 0x0400_002C;
-
-/// BG3 Affine Param A (video mode 2)
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const BG3PA_ADDR: usize = 0x0400_0030;
-
-/// BG3 Affine Param B (video mode 2)
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const BG3PB_ADDR: usize = 0x0400_0032;
-
-/// BG3 Affine Param C (video mode 2)
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const BG3PC_ADDR: usize = 0x0400_0034;
-
-/// BG3 Affine Param D (video mode 2)
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const BG3PD_ADDR: usize = 0x0400_0036;
-
-/// BG3 X reference point (video mode 2)
-///
-/// * **Access:** write-only
-/// * **Size:** 4
-pub const BG3X_ADDR: usize = 0x0400_0038;
-
-/// BG3 Y reference point (video mode 2)
-///
-/// * **Access:** write-only
-/// * **Size:** 4
-pub const BG3Y_ADDR: usize = 0x0400_003C;
-
-// // // // //
-// Window
-// // // // //
-
-/// Window 0 horizontal.
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const WIN0H_ADDR: usize = 0x0400_0040;
-
-/// Window 1 horizontal.
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const WIN1H_ADDR: usize = 0x0400_0042;
-
-/// Window 0 vertical.
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const WIN0V_ADDR: usize = 0x0400_0044;
-
-/// Window 0 vertical.
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const WIN1V_ADDR: usize = 0x0400_0046;
-
-/// Window 0 inside display.
-///
-/// * **Access:** read/write
-/// * **Size:** 1
-pub const WIN0_IN_ADDR: usize = 0x0400_0048;
-
-/// Window 1 inside display.
-///
-/// * **Access:** read/write
-/// * **Size:** 1
-pub const WIN1_IN_ADDR: usize = 0x0400_0049;
-
-/// Outside window display.
-///
-/// * **Access:** read/write
-/// * **Size:** 1
-pub const WIN_OUT_ADDR: usize = 0x0400_004A;
-
-/// Object window display.
-///
-/// * **Access:** read/write
-/// * **Size:** 1
-pub const OBJ_WIN_ADDR: usize = 0x0400_004B;
-
-// // // // //
-// Special Effects
-// // // // //
-
-/// Mosaic effect size.
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const MOSAIC_ADDR: usize = 0x0400_004C;
-
-/// Color blend special effect.
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const BLDCNT_ADDR: usize = 0x0400_0050;
-
-/// Alpha blend `EVA`.
-///
-/// * **Access:** read/write
-/// * **Size:** 1
-pub const BLDALPHA_A_ADDR: usize = 0x0400_0052;
-
-/// Alpha blend `EVB`.
-///
-/// * **Access:** read/write
-/// * **Size:** 1
-pub const BLDALPHA_B_ADDR: usize = 0x0400_0053;
-
-/// Brightness blend `EVY`.
-///
-/// * **Access:** write-only
-/// * **Size:** 1
-pub const BLDY_ADDR: usize = 0x0400_0054;
+use super::*;
+
+/// How a register can be accessed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RegisterAccess {
+  /// The register can only be read.
+  ReadOnly,
+  /// The register can only be written.
+  WriteOnly,
+  /// The register can be both read and written.
+  ReadWrite,
+}
+impl RegisterAccess {
+  /// If the register can be read.
+  pub const fn can_read(self) -> bool {
+    !matches!(self, RegisterAccess::WriteOnly)
+  }
+
+  /// If the register can be written.
+  pub const fn can_write(self) -> bool {
+    !matches!(self, RegisterAccess::ReadOnly)
+  }
+}
+
+/// The part of the hardware that a register controls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IoPeripheral {
+  /// Display control and status.
+  Display,
+  /// Background control, offsets, and affine parameters.
+  Background,
+  /// Window bounds and window layer selection.
+  Window,
+  /// Mosaic and color special effects.
+  Blend,
+  /// Sound channels, mixing, and the DMA sound FIFOs.
+  Sound,
+  /// The four DMA units.
+  Dma,
+  /// The four timers.
+  Timer,
+  /// Serial communication.
+  Serial,
+  /// Key input and key interrupts.
+  Keypad,
+  /// Interrupt enable, flags, and master enable.
+  Interrupt,
+  /// Other system control.
+  System,
+}
+
+/// Info about a single IO register.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RegisterInfo {
+  /// The name of the register's constant (eg: `"DISPCNT_ADDR"`).
+  pub name: &'static str,
+  /// The address of the register.
+  pub addr: usize,
+  /// The size of the register, in bytes.
+  pub size: usize,
+  /// How the register can be accessed.
+  pub access: RegisterAccess,
+  /// The part of the hardware that the register controls.
+  pub region: IoPeripheral,
+}
+
+/// Info about a field within a register value type.
+///
+/// The value types for registers each have a `FIELDS` constant listing these,
+/// for tools that need the field layout as data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FieldInfo {
+  /// The name of the field's getter method.
+  pub name: &'static str,
+  /// The lowest bit of the field.
+  pub lsb: u32,
+  /// The number of bits in the field.
+  pub width: u32,
+}
+impl FieldInfo {
+  /// Makes a new field info value.
+  pub const fn new(name: &'static str, lsb: u32, width: u32) -> Self {
+    Self { name, lsb, width }
+  }
+}
+
+/// Base address of the IO registers.
+pub const IO_START_ADDR: usize = 0x0400_0000;
+
+/// The IO registers span 1kb (though much of it is unused).
+pub const IO_COUNT: usize = 0x400;
+
+/// Access info for the IO registers.
+pub const IO_REGION_INFO: RegionInfo = RegionInfo {
+  bus_width: AccessWidth::Bits32,
+  reads: AccessWidths::ALL,
+  writes: AccessWidths::ALL,
+  wait_states: 0,
+  dma_source: true,
+  dma_destination: true,
+};
+
+macro_rules! access_doc {
+  (ReadOnly) => {
+    " * **Access:** read-only"
+  };
+  (WriteOnly) => {
+    " * **Access:** write-only"
+  };
+  (ReadWrite) => {
+    " * **Access:** read/write"
+  };
+}
+
+#[cfg(feature = "volatile")]
+macro_rules! vol_type {
+  (1, $r:ty, $w:ty) => {
+    VolAddress<u8, $r, $w>
+  };
+  (2, $r:ty, $w:ty) => {
+    VolAddress<u16, $r, $w>
+  };
+  (4, $r:ty, $w:ty) => {
+    VolAddress<u32, $r, $w>
+  };
+  (16, $r:ty, $w:ty) => {
+    VolAddress<[u32; 4], $r, $w>
+  };
+  ($size:tt, ReadOnly) => {
+    vol_type!($size, Safe, ())
+  };
+  ($size:tt, WriteOnly) => {
+    vol_type!($size, (), Safe)
+  };
+  ($size:tt, ReadWrite) => {
+    vol_type!($size, Safe, Safe)
+  };
+}
+
+/// Declares each register's address constant along with its entry in
+/// [`IO_REGISTERS`] (and its typed version, if enabled), so that they can't
+/// ever disagree.
+macro_rules! io_registers {
+  ($(
+    $region:ident {
+      $(
+        $(#[$meta:meta])*
+        $name:ident: $addr:literal, $access:ident, $size:tt;
+      )*
+    }
+  )*) => {
+    $($(
+      $(#[$meta])*
+      ///
+      #[doc = access_doc!($access)]
+      #[doc = concat!(" * **Size:** ", stringify!($size))]
+      pub const $name: usize = $addr;
+    )*)*
+
+    /// Info on every IO register, in the same order they're declared.
+    ///
+    /// Note that some registers overlap (eg: [`SIODATA32_ADDR`] and
+    /// [`SIOMULTI0_ADDR`]), and so some addresses appear more than once.
+    pub const IO_REGISTERS: &[RegisterInfo] = &[$($(
+      RegisterInfo {
+        name: stringify!($name),
+        addr: $name,
+        size: $size,
+        access: RegisterAccess::$access,
+        region: IoPeripheral::$region,
+      },
+    )*)*];
+
+    /// Typed volatile addresses for each IO register.
+    ///
+    /// Each constant has the same name as the raw address that it wraps, and
+    /// the read/write access of each is encoded into its type.
+    ///
+    /// This module is only available with the `volatile` feature.
+    #[cfg(feature = "volatile")]
+    pub mod vol {
+      use super::*;
+
+      $($(
+        $(#[$meta])*
+        pub const $name: vol_type!($size, $access) =
+          unsafe { VolAddress::new(super::$name) };
+      )*)*
+    }
+  };
+}
+
+io_registers! {
+  Display {
+    /// Display Control
+    DISPCNT_ADDR: 0x0400_0000, ReadWrite, 2;
+
+    /// Display Status
+    DISPSTAT_ADDR: 0x0400_0004, ReadWrite, 2;
+
+    /// Vertical Counter
+    VCOUNT_ADDR: 0x0400_0006, ReadOnly, 2;
+  }
+
+  Background {
+    // BG Control
+
+    /// BG0 Control (video modes 0 or 1)
+    BG0CNT_ADDR: 0x0400_0008, ReadWrite, 2;
+
+    /// BG1 Control (video modes 0 or 1)
+    BG1CNT_ADDR: 0x0400_000A, ReadWrite, 2;
+
+    /// BG2 Control (video modes 0, 1, or 2)
+    BG2CNT_ADDR: 0x0400_000C, ReadWrite, 2;
+
+    /// BG3 Control (video modes 0 or 2)
+    BG3CNT_ADDR: 0x0400_000E, ReadWrite, 2;
+
+    // Text Offsets
+
+    /// BG0 Horizontal Offset (video modes 0 or 1)
+    BG0HOFS_ADDR: 0x0400_0010, WriteOnly, 2;
+
+    /// BG0 Vertical Offset (video modes 0 or 1)
+    BG0VOFS_ADDR: 0x0400_0012, WriteOnly, 2;
+
+    /// BG1 Horizontal Offset (video modes 0 or 1)
+    BG1HOFS_ADDR: 0x0400_0014, WriteOnly, 2;
+
+    /// BG1 Vertical Offset (video modes 0 or 1)
+    BG1VOFS_ADDR: 0x0400_0016, WriteOnly, 2;
+
+    /// BG2 Horizontal Offset (video mode 0)
+    BG2HOFS_ADDR: 0x0400_0018, WriteOnly, 2;
 
-// // // // //
-// Sound
-// // // // //
+    /// BG2 Vertical Offset (video mode 0)
+    BG2VOFS_ADDR: 0x0400_001A, WriteOnly, 2;
 
-/// Channel 1 Sweep register.
-///
-/// * **Access:** read/write
-/// * **Size:** 1
-pub const CHANNEL1_SWEEP: usize = 0x0400_0060;
+    /// BG3 Horizontal Offset (video mode 0)
+    BG3HOFS_ADDR: 0x0400_001C, WriteOnly, 2;
 
-/// Channel 1 duty / len / envelope
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const CHANNEL1_DUTY_LEN_ENV: usize = 0x0400_0062;
+    /// BG3 Vertical Offset (video mode 0)
+    BG3VOFS_ADDR: 0x0400_001E, WriteOnly, 2;
 
-/// Channel 1 frequency / control
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const CHANNEL1_FREQ_CTRL: usize = 0x0400_0064;
-
-/// Channel 2 duty / len / envelope
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const CHANNEL2_DUTY_LEN_ENV: usize = 0x0400_0068;
+    // Affine Parameters
 
-/// Channel 2 frequency / control
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const CHANNEL2_FREQ_CTRL: usize = 0x0400_006C;
+    /// BG2 Affine Param A (video mode 1 and 2)
+    BG2PA_ADDR: 0x0400_0020, WriteOnly, 2;
 
-/// Channel 3 stop / wave RAM select
-///
-/// * **Access:** read/write
-/// * **Size:** 1
-pub const CHANNEL3_SELECT: usize = 0x0400_0070;
+    /// BG2 Affine Param B (video mode 1 and 2)
+    BG2PB_ADDR: 0x0400_0022, WriteOnly, 2;
 
-/// Channel 3 len
-///
-/// * **Access:** write
-/// * **Size:** 1
-pub const CHANNEL3_LEN: usize = 0x0400_0072;
+    /// BG2 Affine Param C (video mode 1 and 2)
+    BG2PC_ADDR: 0x0400_0024, WriteOnly, 2;
 
-/// Channel 3 volume
-///
-/// * **Access:** read/write
-/// * **Size:** 1
-pub const CHANNEL3_VOLUME: usize = 0x0400_0073;
+    /// BG2 Affine Param D (video mode 1 and 2)
+    BG2PD_ADDR: 0x0400_0026, WriteOnly, 2;
 
-/// Channel 3 frequency / control
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const CHANNEL3_FREQ_CTRL: usize = 0x0400_0074;
+    /// BG2 X reference point (video mode 1, 2, 3, 4, and 5)
+    BG2X_ADDR: 0x0400_0028, WriteOnly, 4;
 
-/// Channel 4 len / envelope
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const CHANNEL4_LEN_ENV: usize = 0x0400_0078;
+    /// BG2 Y reference point (video mode 1, 2, 3, 4, and 5)
+    BG2Y_ADDR: 0x0400_002C, WriteOnly, 4;
 
-/// Channel 4 frequency / control
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const CHANNEL4_FREQ_CTRL: usize = 0x0400_007C;
+    /// BG3 Affine Param A (video mode 2)
+    BG3PA_ADDR: 0x0400_0030, WriteOnly, 2;
 
-/// Sound Control left/right volume/enable
-///
-/// * **Access:** read/write
-/// * **Size:** 1
-pub const CHANNELS_LEFT_RIGHT_VOLUME: usize = 0x0400_0080;
+    /// BG3 Affine Param B (video mode 2)
+    BG3PB_ADDR: 0x0400_0032, WriteOnly, 2;
 
-/// Sound Control left/right volume/enable
-///
-/// * **Access:** read/write
-/// * **Size:** 1
-pub const CHANNELS_LEFT_RIGHT_ENABLED: usize = 0x0400_0081;
+    /// BG3 Affine Param C (video mode 2)
+    BG3PC_ADDR: 0x0400_0034, WriteOnly, 2;
 
-/// DMA Sound Control / Mixing
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const DMA_MIXING_CTRL: usize = 0x0400_0082;
+    /// BG3 Affine Param D (video mode 2)
+    BG3PD_ADDR: 0x0400_0036, WriteOnly, 2;
 
-/// Sound on/off
-///
-/// * **Access:** read/write
-/// * **Size:** 1
-pub const SOUND_ENABLED_CTRL: usize = 0x0400_0084;
+    /// BG3 X reference point (video mode 2)
+    BG3X_ADDR: 0x0400_0038, WriteOnly, 4;
 
-/// Final sound output bias.
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const SOUNDBIAS: usize = 0x0400_0088;
+    /// BG3 Y reference point (video mode 2)
+    BG3Y_ADDR: 0x0400_003C, WriteOnly, 4;
+  }
 
-/// Wave RAM data.
-///
-/// This is actually two banks. While you can access one bank here, the other
-/// bank is the sound playing.
-///
-/// When the sound is played, the entire 32-bit region is rotated, 4 bits at a
-/// time, and then the lowest 4 bits are played as that sample.
-///
-/// * **Access:** read/write
-/// * **Size:** 32 (used as 4-bit samples)
-pub const WAVE_RAM_BASE_ADDR: usize = 0x0400_0090;
+  Window {
+    /// Window 0 horizontal.
+    WIN0H_ADDR: 0x0400_0040, WriteOnly, 2;
 
-/// FIFO sound target for sound using DMA 1.
-///
-/// * **Access:** write-only
-/// * **Size:** 4
-pub const FIFO_A_ADDR: usize = 0x0400_00A0;
+    /// Window 1 horizontal.
+    WIN1H_ADDR: 0x0400_0042, WriteOnly, 2;
 
-/// FIFO sound target for sound using DMA 2.
-///
-/// * **Access:** write-only
-/// * **Size:** 4
-pub const FIFO_B_ADDR: usize = 0x0400_00A4;
+    /// Window 0 vertical.
+    WIN0V_ADDR: 0x0400_0044, WriteOnly, 2;
 
-// // // // //
-// DMA
-// // // // //
+    /// Window 1 vertical.
+    WIN1V_ADDR: 0x0400_0046, WriteOnly, 2;
 
-/// DMA 0 Source Address
-///
-/// * **Access:** write-only
-/// * **Size:** 4
-pub const DMA0SAD_ADDR: usize = 0x0400_00B0;
+    /// Window 0 inside display.
+    WIN0_IN_ADDR: 0x0400_0048, ReadWrite, 1;
 
-/// DMA 0 Destination Address
-///
-/// * **Access:** write-only
-/// * **Size:** 4
-pub const DMA0DAD_ADDR: usize = 0x0400_00B4;
+    /// Window 1 inside display.
+    WIN1_IN_ADDR: 0x0400_0049, ReadWrite, 1;
 
-/// DMA 0 transfer count
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const DMA0CNT_L_ADDR: usize = 0x0400_00B8;
+    /// Outside window display.
+    WIN_OUT_ADDR: 0x0400_004A, ReadWrite, 1;
 
-/// DMA 0 control bits
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const DMA0CNT_H_ADDR: usize = 0x0400_00BA;
+    /// Object window display.
+    OBJ_WIN_ADDR: 0x0400_004B, ReadWrite, 1;
+  }
 
-/// DMA 1 Source Address
-///
-/// * **Access:** write-only
-/// * **Size:** 4
-pub const DMA1SAD_ADDR: usize = 0x0400_00BC;
+  Blend {
+    /// Mosaic effect size.
+    MOSAIC_ADDR: 0x0400_004C, WriteOnly, 2;
 
-/// DMA 1 Destination Address
-///
-/// * **Access:** write-only
-/// * **Size:** 4
-pub const DMA1DAD_ADDR: usize = 0x0400_00C0;
+    /// Color blend special effect.
+    BLDCNT_ADDR: 0x0400_0050, ReadWrite, 2;
 
-/// DMA 1 transfer count
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const DMA1CNT_L_ADDR: usize = 0x0400_00C4;
+    /// Alpha blend `EVA`.
+    BLDALPHA_A_ADDR: 0x0400_0052, ReadWrite, 1;
 
-/// DMA 1 control bits
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const DMA1CNT_H_ADDR: usize = 0x0400_00C6;
+    /// Alpha blend `EVB`.
+    BLDALPHA_B_ADDR: 0x0400_0053, ReadWrite, 1;
 
-/// DMA 2 Source Address
-///
-/// * **Access:** write-only
-/// * **Size:** 4
-pub const DMA2SAD_ADDR: usize = 0x0400_00C8;
+    /// Brightness blend `EVY`.
+    BLDY_ADDR: 0x0400_0054, WriteOnly, 1;
+  }
 
-/// DMA 2 Destination Address
-///
-/// * **Access:** write-only
-/// * **Size:** 4
-pub const DMA2DAD_ADDR: usize = 0x0400_00CC;
+  Sound {
+    /// Channel 1 Sweep register.
+    CHANNEL1_SWEEP: 0x0400_0060, ReadWrite, 1;
 
-/// DMA 2 transfer count
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const DMA2CNT_L_ADDR: usize = 0x0400_00D0;
+    /// Channel 1 duty / len / envelope
+    CHANNEL1_DUTY_LEN_ENV: 0x0400_0062, ReadWrite, 2;
 
-/// DMA 2 control bits
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const DMA2CNT_H_ADDR: usize = 0x0400_00D2;
+    /// Channel 1 frequency / control
+    CHANNEL1_FREQ_CTRL: 0x0400_0064, ReadWrite, 2;
 
-/// DMA 3 Source Address
-///
-/// * **Access:** write-only
-/// * **Size:** 4
-pub const DMA3SAD_ADDR: usize = 0x0400_00D4;
+    /// Channel 2 duty / len / envelope
+    CHANNEL2_DUTY_LEN_ENV: 0x0400_0068, ReadWrite, 2;
 
-/// DMA 3 Destination Address
-///
-/// * **Access:** write-only
-/// * **Size:** 4
-pub const DMA3DAD_ADDR: usize = 0x0400_00D8;
+    /// Channel 2 frequency / control
+    CHANNEL2_FREQ_CTRL: 0x0400_006C, ReadWrite, 2;
 
-/// DMA 3 transfer count
-///
-/// * **Access:** write-only
-/// * **Size:** 2
-pub const DMA3CNT_L_ADDR: usize = 0x0400_00DC;
+    /// Channel 3 stop / wave RAM select
+    CHANNEL3_SELECT: 0x0400_0070, ReadWrite, 1;
 
-/// DMA 3 control bits
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const DMA3CNT_H_ADDR: usize = 0x0400_00DE;
+    /// Channel 3 len
+    CHANNEL3_LEN: 0x0400_0072, WriteOnly, 1;
 
-// // // // //
-// Timers
-// // // // //
+    /// Channel 3 volume
+    CHANNEL3_VOLUME: 0x0400_0073, ReadWrite, 1;
 
-/// Timer 0 counter / reload
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const TM0CNT_L_ADDR: usize = 0x0400_0100;
+    /// Channel 3 frequency / control
+    CHANNEL3_FREQ_CTRL: 0x0400_0074, ReadWrite, 2;
 
-/// Timer 0 control bits
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const TM0CNT_H_ADDR: usize = 0x0400_0102;
+    /// Channel 4 len / envelope
+    CHANNEL4_LEN_ENV: 0x0400_0078, ReadWrite, 2;
 
-/// Timer 1 counter / reload
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const TM1CNT_L_ADDR: usize = 0x0400_0104;
+    /// Channel 4 frequency / control
+    CHANNEL4_FREQ_CTRL: 0x0400_007C, ReadWrite, 2;
 
-/// Timer 1 control bits
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const TM1CNT_H_ADDR: usize = 0x0400_0106;
+    /// Sound Control left/right volume/enable
+    CHANNELS_LEFT_RIGHT_VOLUME: 0x0400_0080, ReadWrite, 1;
 
-/// Timer 2 counter / reload
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const TM2CNT_L_ADDR: usize = 0x0400_0108;
+    /// Sound Control left/right volume/enable
+    CHANNELS_LEFT_RIGHT_ENABLED: 0x0400_0081, ReadWrite, 1;
 
-/// Timer 2 control bits
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const TM2CNT_H_ADDR: usize = 0x0400_010A;
+    /// DMA Sound Control / Mixing
+    DMA_MIXING_CTRL: 0x0400_0082, ReadWrite, 2;
 
-/// Timer 3 counter / reload
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const TM3CNT_L_ADDR: usize = 0x0400_010C;
+    /// Sound on/off
+    SOUND_ENABLED_CTRL: 0x0400_0084, ReadWrite, 1;
 
-/// Timer 3 control bits
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const TM3CNT_H_ADDR: usize = 0x0400_010E;
+    /// Final sound output bias.
+    SOUNDBIAS: 0x0400_0088, ReadWrite, 2;
 
-// // // // //
-// Serial 1
-// // // // //
+    /// Wave RAM data.
+    ///
+    /// This is actually two banks. While you can access one bank here, the
+    /// other bank is the sound playing.
+    ///
+    /// When the sound is played, the entire 32-bit region is rotated, 4 bits at
+    /// a time, and then the lowest 4 bits are played as that sample.
+    ///
+    /// The 16 bytes here are used as 32 4-bit samples.
+    WAVE_RAM_BASE_ADDR: 0x0400_0090, ReadWrite, 16;
 
-/// ?
-///
-/// * **Access:** read/write
-/// * **Size:** 4
-pub const SIODATA32_ADDR: usize = 0x0400_0120;
+    /// FIFO sound target for sound using DMA 1.
+    FIFO_A_ADDR: 0x0400_00A0, WriteOnly, 4;
 
-/// ?
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const SIOMULTI0_ADDR: usize = 0x0400_0120;
+    /// FIFO sound target for sound using DMA 2.
+    FIFO_B_ADDR: 0x0400_00A4, WriteOnly, 4;
+  }
 
-/// ?
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const SIOMULTI1_ADDR: usize = 0x0400_0122;
+  Dma {
+    /// DMA 0 Source Address
+    DMA0SAD_ADDR: 0x0400_00B0, WriteOnly, 4;
 
-/// ?
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const SIOMULTI2_ADDR: usize = 0x0400_0124;
+    /// DMA 0 Destination Address
+    DMA0DAD_ADDR: 0x0400_00B4, WriteOnly, 4;
 
-/// ?
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const SIOMULTI3_ADDR: usize = 0x0400_0126;
+    /// DMA 0 transfer count
+    DMA0CNT_L_ADDR: 0x0400_00B8, WriteOnly, 2;
 
-/// ?
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const SIOCNT_ADDR: usize = 0x0400_0128;
+    /// DMA 0 control bits
+    DMA0CNT_H_ADDR: 0x0400_00BA, ReadWrite, 2;
 
-/// ?
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const SIOMLT_SEND_ADDR: usize = 0x0400_012A;
+    /// DMA 1 Source Address
+    DMA1SAD_ADDR: 0x0400_00BC, WriteOnly, 4;
 
-/// ?
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const SIODATA8_ADDR: usize = 0x0400_012A;
+    /// DMA 1 Destination Address
+    DMA1DAD_ADDR: 0x0400_00C0, WriteOnly, 4;
 
-// // // // //
-// Keypad
-// // // // //
+    /// DMA 1 transfer count
+    DMA1CNT_L_ADDR: 0x0400_00C4, WriteOnly, 2;
 
-/// Keys pressed
-///
-/// * 0: A
-/// * 1: B
-/// * 2: Select
-/// * 3: Start
-/// * 4: Right
-/// * 5: Left
-/// * 6: Up
-/// * 7: Down
-/// * 8: R
-/// * 9: L
-///
-/// Bits use a "low active" convention: 0 when pressed, 1 when released.
-///
-/// * **Access:** read-only
-/// * **Size:** 2
-pub const KEYINPUT_ADDR: usize = 0x0400_0130;
+    /// DMA 1 control bits
+    DMA1CNT_H_ADDR: 0x0400_00C6, ReadWrite, 2;
 
-/// Keypad interrupt control
-///
-/// Uses the same bit ordering as the [`KEYINPUT_ADDR`](KEYINPUT_ADDR).
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const KEYCNT_ADDR: usize = 0x0400_0132;
+    /// DMA 2 Source Address
+    DMA2SAD_ADDR: 0x0400_00C8, WriteOnly, 4;
 
-// // // // //
-// Serial 2
-// // // // //
+    /// DMA 2 Destination Address
+    DMA2DAD_ADDR: 0x0400_00CC, WriteOnly, 4;
 
-/// ?
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const RCNT_ADDR: usize = 0x0400_0134;
+    /// DMA 2 transfer count
+    DMA2CNT_L_ADDR: 0x0400_00D0, WriteOnly, 2;
 
-/// ?
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const JOYCNT_ADDR: usize = 0x0400_0140;
+    /// DMA 2 control bits
+    DMA2CNT_H_ADDR: 0x0400_00D2, ReadWrite, 2;
 
-/// ?
-///
-/// * **Access:** read/write
-/// * **Size:** 4
-pub const JOY_RECV_ADDR: usize = 0x0400_0150;
+    /// DMA 3 Source Address
+    DMA3SAD_ADDR: 0x0400_00D4, WriteOnly, 4;
 
-/// ?
-///
-/// * **Access:** read/write
-/// * **Size:** 4
-pub const JOY_TRANS_ADDR: usize = 0x0400_0154;
+    /// DMA 3 Destination Address
+    DMA3DAD_ADDR: 0x0400_00D8, WriteOnly, 4;
 
-/// ?
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const JOYSTAT_ADDR: usize = 0x0400_0158;
+    /// DMA 3 transfer count
+    DMA3CNT_L_ADDR: 0x0400_00DC, WriteOnly, 2;
 
-// // // // //
-// Interrupt / Waitstate
-// // // // //
+    /// DMA 3 control bits
+    DMA3CNT_H_ADDR: 0x0400_00DE, ReadWrite, 2;
+  }
 
-/// Interrupt Enable
-///
-/// * 0: Vertical blank
-/// * 1: Horizontal blank
-/// * 2: `VCOUNT` matches `DISPCNT` value.
-/// * 3: Timer 0 Overflow
-/// * 4: Timer 1 Overflow
-/// * 5: Timer 2 Overflow
-/// * 6: Timer 3 Overflow
-/// * 7: Serial Communication
-/// * 8: DMA 0
-/// * 9: DMA 1
-/// * 10: DMA 2
-/// * 11: DMA 3
-/// * 12: Keypad
-///
-/// Controls when the system will *accept* an interrupt from each source. If an
-/// interrupt type isn't set here, then interrupts from that source will be
-/// ignored.
-///
-/// Note that most sources actually *send* an interrupt or not based on their
-/// own control register values elsewhere.
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const IE_ADDR: usize = 0x0400_0200;
+  Timer {
+    /// Timer 0 counter / reload
+    TM0CNT_L_ADDR: 0x0400_0100, ReadWrite, 2;
 
-/// Interrupt Flags
-///
-/// These bits are set during an interrupt. To acknowledge an interrupt during
-/// the interrupt handler, write a "1" to one or more bits within this register.
-///
-/// Note that if you're acknowledging an interrupt within
-/// `IntrWait`/`VBlankIntrWait` then you must also BIT_OR your changes to the
-/// `IF` address to the
-/// [`IRQ_INTR_WAIT_CHECK_FLAG_ADDR`](IRQ_INTR_WAIT_CHECK_FLAG_ADDR).
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const IF_ADDR: usize = 0x0400_0202;
+    /// Timer 0 control bits
+    TM0CNT_H_ADDR: 0x0400_0102, ReadWrite, 2;
 
-/// Waitstate Control
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const WAITCNT_ADDR: usize = 0x0400_0204;
+    /// Timer 1 counter / reload
+    TM1CNT_L_ADDR: 0x0400_0104, ReadWrite, 2;
 
-/// Interrupt Master Enable
-///
-/// Bit 0 controls if interrupts can happen, all other bits are ignored.
-///
-/// Note that calling `IntrWait` or `VBlankIntrWait` will force bit 0 on.
-///
-/// * **Access:** read/write
-/// * **Size:** 2
-pub const IME_ADDR: usize = 0x0400_0208;
+    /// Timer 1 control bits
+    TM1CNT_H_ADDR: 0x0400_0106, ReadWrite, 2;
+
+    /// Timer 2 counter / reload
+    TM2CNT_L_ADDR: 0x0400_0108, ReadWrite, 2;
+
+    /// Timer 2 control bits
+    TM2CNT_H_ADDR: 0x0400_010A, ReadWrite, 2;
+
+    /// Timer 3 counter / reload
+    TM3CNT_L_ADDR: 0x0400_010C, ReadWrite, 2;
+
+    /// Timer 3 control bits
+    TM3CNT_H_ADDR: 0x0400_010E, ReadWrite, 2;
+  }
+
+  Serial {
+    /// ?
+    SIODATA32_ADDR: 0x0400_0120, ReadWrite, 4;
+
+    /// ?
+    SIOMULTI0_ADDR: 0x0400_0120, ReadWrite, 2;
+
+    /// ?
+    SIOMULTI1_ADDR: 0x0400_0122, ReadWrite, 2;
+
+    /// ?
+    SIOMULTI2_ADDR: 0x0400_0124, ReadWrite, 2;
+
+    /// ?
+    SIOMULTI3_ADDR: 0x0400_0126, ReadWrite, 2;
+
+    /// ?
+    SIOCNT_ADDR: 0x0400_0128, ReadWrite, 2;
+
+    /// ?
+    SIOMLT_SEND_ADDR: 0x0400_012A, ReadWrite, 2;
+
+    /// ?
+    SIODATA8_ADDR: 0x0400_012A, ReadWrite, 2;
+  }
+
+  Keypad {
+    /// Keys pressed
+    ///
+    /// * 0: A
+    /// * 1: B
+    /// * 2: Select
+    /// * 3: Start
+    /// * 4: Right
+    /// * 5: Left
+    /// * 6: Up
+    /// * 7: Down
+    /// * 8: R
+    /// * 9: L
+    ///
+    /// Bits use a "low active" convention: 0 when pressed, 1 when released.
+    KEYINPUT_ADDR: 0x0400_0130, ReadOnly, 2;
+
+    /// Keypad interrupt control
+    ///
+    /// Uses the same bit ordering as the [`KEYINPUT_ADDR`](KEYINPUT_ADDR).
+    KEYCNT_ADDR: 0x0400_0132, ReadWrite, 2;
+  }
+
+  Serial {
+    /// ?
+    RCNT_ADDR: 0x0400_0134, ReadWrite, 2;
+
+    /// ?
+    JOYCNT_ADDR: 0x0400_0140, ReadWrite, 2;
+
+    /// ?
+    JOY_RECV_ADDR: 0x0400_0150, ReadWrite, 4;
+
+    /// ?
+    JOY_TRANS_ADDR: 0x0400_0154, ReadWrite, 4;
+
+    /// ?
+    JOYSTAT_ADDR: 0x0400_0158, ReadWrite, 2;
+  }
+
+  Interrupt {
+    /// Interrupt Enable
+    ///
+    /// * 0: Vertical blank
+    /// * 1: Horizontal blank
+    /// * 2: `VCOUNT` matches `DISPCNT` value.
+    /// * 3: Timer 0 Overflow
+    /// * 4: Timer 1 Overflow
+    /// * 5: Timer 2 Overflow
+    /// * 6: Timer 3 Overflow
+    /// * 7: Serial Communication
+    /// * 8: DMA 0
+    /// * 9: DMA 1
+    /// * 10: DMA 2
+    /// * 11: DMA 3
+    /// * 12: Keypad
+    /// * 13: Game Pak
+    ///
+    /// Controls when the system will *accept* an interrupt from each source. If
+    /// an interrupt type isn't set here, then interrupts from that source will
+    /// be ignored.
+    ///
+    /// Note that most sources actually *send* an interrupt or not based on their
+    /// own control register values elsewhere.
+    IE_ADDR: 0x0400_0200, ReadWrite, 2;
+
+    /// Interrupt Flags
+    ///
+    /// These bits are set during an interrupt. To acknowledge an interrupt
+    /// during the interrupt handler, write a "1" to one or more bits within this
+    /// register.
+    ///
+    /// Note that if you're acknowledging an interrupt within
+    /// `IntrWait`/`VBlankIntrWait` then you must also BIT_OR your changes to the
+    /// `IF` address to the [`IRQ_INTR_WAIT_CHECK_FLAG_ADDR`].
+    IF_ADDR: 0x0400_0202, ReadWrite, 2;
+  }
+
+  System {
+    /// Waitstate Control
+    WAITCNT_ADDR: 0x0400_0204, ReadWrite, 2;
+  }
+
+  Interrupt {
+    /// Interrupt Master Enable
+    ///
+    /// Bit 0 controls if interrupts can happen, all other bits are ignored.
+    ///
+    /// Note that calling `IntrWait` or `VBlankIntrWait` will force bit 0 on.
+    IME_ADDR: 0x0400_0208, ReadWrite, 2;
+  }
+}
+
+/// Gets the info of the IO register that contains an address.
+///
+/// The address can be anywhere within the register, such as the upper half of
+/// [`BG2X_ADDR`]. Where registers overlap, the first one listed in
+/// [`IO_REGISTERS`] is returned.
+///
+/// Returns `None` if no register contains the address.
+pub const fn register_at(addr: usize) -> Option<&'static RegisterInfo> {
+  let mut i = 0;
+  while i < IO_REGISTERS.len() {
+    let reg = &IO_REGISTERS[i];
+    if reg.addr <= addr && addr < reg.addr + reg.size {
+      return Some(reg);
+    }
+    i += 1;
+  }
+  None
+}
+
+/// Gets the name of the IO register that contains an address, along with the
+/// byte offset of the address within that register.
+///
+/// For example, `name_of(0x0400_002A)` is `Some(("BG2X_ADDR", 2))`.
+///
+/// Returns `None` if no register contains the address.
+pub const fn name_of(addr: usize) -> Option<(&'static str, usize)> {
+  match register_at(addr) {
+    Some(reg) => Some((reg.name, addr - reg.addr)),
+    None => None,
+  }
+}
+
+/// There are 4 backgrounds.
+pub const BG_COUNT: usize = 4;
+
+/// The distance between one `BGxCNT` register and the next.
+pub const BG_CNT_STRIDE: usize = BG1CNT_ADDR - BG0CNT_ADDR;
+const_assert!(BG3CNT_ADDR == BG0CNT_ADDR + 3 * BG_CNT_STRIDE);
+
+/// The distance between one background's offset registers and the next.
+pub const BG_OFS_STRIDE: usize = BG1HOFS_ADDR - BG0HOFS_ADDR;
+const_assert!(BG3HOFS_ADDR == BG0HOFS_ADDR + 3 * BG_OFS_STRIDE);
+const_assert!(BG3VOFS_ADDR == BG0VOFS_ADDR + 3 * BG_OFS_STRIDE);
+
+/// Index to a given background's control register.
+///
+/// ## Panics
+/// `i` must be < 4.
+pub const fn bg_cnt(i: usize) -> usize {
+  let checked_index = const_bound_check(i, BG_COUNT);
+  BG0CNT_ADDR + (BG_CNT_STRIDE * checked_index)
+}
+
+/// Index to a given background's horizontal offset register.
+///
+/// ## Panics
+/// `i` must be < 4.
+pub const fn bg_hofs(i: usize) -> usize {
+  let checked_index = const_bound_check(i, BG_COUNT);
+  BG0HOFS_ADDR + (BG_OFS_STRIDE * checked_index)
+}
+
+/// Index to a given background's vertical offset register.
+///
+/// ## Panics
+/// `i` must be < 4.
+pub const fn bg_vofs(i: usize) -> usize {
+  let checked_index = const_bound_check(i, BG_COUNT);
+  BG0VOFS_ADDR + (BG_OFS_STRIDE * checked_index)
+}
+
+/// The distance between the BG2 and BG3 affine registers.
+pub const BG_AFFINE_STRIDE: usize = BG3PA_ADDR - BG2PA_ADDR;
+const_assert!(BG3X_ADDR == BG2X_ADDR + BG_AFFINE_STRIDE);
+
+/// Index to the `PA` register of an affine background. The other registers
+/// follow it in the order `PB`, `PC`, `PD`, `X`, `Y`.
+///
+/// ## Panics
+/// `bg` must be 2 or 3.
+pub const fn bg_affine_addr(bg: usize) -> usize {
+  let checked_index = const_bound_check(bg.wrapping_sub(2), 2);
+  BG2PA_ADDR + (BG_AFFINE_STRIDE * checked_index)
+}
+
+/// There are 4 DMA units.
+pub const DMA_COUNT: usize = 4;
+
+/// The distance between one DMA unit's registers and the next.
+pub const DMA_STRIDE: usize = DMA1SAD_ADDR - DMA0SAD_ADDR;
+const_assert!(DMA3SAD_ADDR == DMA0SAD_ADDR + 3 * DMA_STRIDE);
+const_assert!(DMA3DAD_ADDR == DMA0DAD_ADDR + 3 * DMA_STRIDE);
+const_assert!(DMA3CNT_L_ADDR == DMA0CNT_L_ADDR + 3 * DMA_STRIDE);
+const_assert!(DMA3CNT_H_ADDR == DMA0CNT_H_ADDR + 3 * DMA_STRIDE);
+
+/// Index to a given DMA unit's source address register.
+///
+/// ## Panics
+/// `i` must be < 4.
+pub const fn dma_sad(i: usize) -> usize {
+  let checked_index = const_bound_check(i, DMA_COUNT);
+  DMA0SAD_ADDR + (DMA_STRIDE * checked_index)
+}
+
+/// Index to a given DMA unit's destination address register.
+///
+/// ## Panics
+/// `i` must be < 4.
+pub const fn dma_dad(i: usize) -> usize {
+  let checked_index = const_bound_check(i, DMA_COUNT);
+  DMA0DAD_ADDR + (DMA_STRIDE * checked_index)
+}
+
+/// Index to a given DMA unit's transfer count register.
+///
+/// ## Panics
+/// `i` must be < 4.
+pub const fn dma_cnt_l(i: usize) -> usize {
+  let checked_index = const_bound_check(i, DMA_COUNT);
+  DMA0CNT_L_ADDR + (DMA_STRIDE * checked_index)
+}
+
+/// Index to a given DMA unit's control register.
+///
+/// ## Panics
+/// `i` must be < 4.
+pub const fn dma_cnt_h(i: usize) -> usize {
+  let checked_index = const_bound_check(i, DMA_COUNT);
+  DMA0CNT_H_ADDR + (DMA_STRIDE * checked_index)
+}
+
+/// There are 4 timers.
+pub const TIMER_COUNT: usize = 4;
+
+/// The distance between one timer's registers and the next.
+pub const TIMER_STRIDE: usize = TM1CNT_L_ADDR - TM0CNT_L_ADDR;
+const_assert!(TM3CNT_L_ADDR == TM0CNT_L_ADDR + 3 * TIMER_STRIDE);
+const_assert!(TM3CNT_H_ADDR == TM0CNT_H_ADDR + 3 * TIMER_STRIDE);
+
+/// Index to a given timer's counter / reload register.
+///
+/// ## Panics
+/// `i` must be < 4.
+pub const fn timer_cnt_l(i: usize) -> usize {
+  let checked_index = const_bound_check(i, TIMER_COUNT);
+  TM0CNT_L_ADDR + (TIMER_STRIDE * checked_index)
+}
+
+/// Index to a given timer's control register.
+///
+/// ## Panics
+/// `i` must be < 4.
+pub const fn timer_cnt_h(i: usize) -> usize {
+  let checked_index = const_bound_check(i, TIMER_COUNT);
+  TM0CNT_H_ADDR + (TIMER_STRIDE * checked_index)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_register_at() {
+    assert_eq!(register_at(DISPCNT_ADDR).unwrap().name, "DISPCNT_ADDR");
+    assert_eq!(register_at(DMA0SAD_ADDR + 2).unwrap().name, "DMA0SAD_ADDR");
+    assert_eq!(register_at(DMA0SAD_ADDR + 4).unwrap().name, "DMA0DAD_ADDR");
+    assert!(register_at(0x0400_020A).is_none());
+    assert!(register_at(IO_START_ADDR + IO_COUNT).is_none());
+  }
+
+  #[test]
+  fn test_name_of() {
+    assert_eq!(name_of(IME_ADDR), Some(("IME_ADDR", 0)));
+    assert_eq!(name_of(0x0400_002A), Some(("BG2X_ADDR", 2)));
+    assert_eq!(name_of(DMA0SAD_ADDR + 3), Some(("DMA0SAD_ADDR", 3)));
+    assert_eq!(name_of(VCOUNT_ADDR + 1), Some(("VCOUNT_ADDR", 1)));
+    assert_eq!(name_of(0x0400_020A), None);
+  }
+}
@@ -0,0 +1,193 @@
+//! Interrupt source bits.
+//!
+//! The same bit layout is used by [`IE_ADDR`] (the sources that are accepted),
+//! [`IF_ADDR`] (the sources that are pending), and
+//! [`IRQ_INTR_WAIT_CHECK_FLAG_ADDR`] (the sources that the BIOS wait functions
+//! are looking for). The [`IrqBits`] type works with all three.
+
+use super::*;
+
+/// A set of interrupt sources.
+///
+/// * 0: VBlank
+/// * 1: HBlank
+/// * 2: VCount
+/// * 3-6: Timer 0 through 3 overflow
+/// * 7: Serial communication
+/// * 8-11: DMA 0 through 3
+/// * 12: Keypad
+/// * 13: Game Pak (removed)
+///
+/// When more than one source is pending, the lowest bit is handled first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct IrqBits(u16);
+impl IrqBits {
+  u16_newtype_basics!();
+
+  /// Info about each field.
+  pub const FIELDS: &'static [FieldInfo] = &[
+    FieldInfo::new("vblank", 0, 1),
+    FieldInfo::new("hblank", 1, 1),
+    FieldInfo::new("vcount", 2, 1),
+    FieldInfo::new("timer0", 3, 1),
+    FieldInfo::new("timer1", 4, 1),
+    FieldInfo::new("timer2", 5, 1),
+    FieldInfo::new("timer3", 6, 1),
+    FieldInfo::new("serial", 7, 1),
+    FieldInfo::new("dma0", 8, 1),
+    FieldInfo::new("dma1", 9, 1),
+    FieldInfo::new("dma2", 10, 1),
+    FieldInfo::new("dma3", 11, 1),
+    FieldInfo::new("keypad", 12, 1),
+    FieldInfo::new("gamepak", 13, 1),
+  ];
+
+  /// No sources.
+  pub const NONE: Self = Self(0);
+  /// The display entered VBlank.
+  pub const VBLANK: Self = Self(1 << 0);
+  /// The display entered HBlank.
+  pub const HBLANK: Self = Self(1 << 1);
+  /// `VCOUNT` matched the [`DisplayStatus`] VCount setting.
+  pub const VCOUNT: Self = Self(1 << 2);
+  /// Timer 0 overflowed.
+  pub const TIMER0: Self = Self(1 << 3);
+  /// Timer 1 overflowed.
+  pub const TIMER1: Self = Self(1 << 4);
+  /// Timer 2 overflowed.
+  pub const TIMER2: Self = Self(1 << 5);
+  /// Timer 3 overflowed.
+  pub const TIMER3: Self = Self(1 << 6);
+  /// Serial communication.
+  pub const SERIAL: Self = Self(1 << 7);
+  /// DMA 0 finished.
+  pub const DMA0: Self = Self(1 << 8);
+  /// DMA 1 finished.
+  pub const DMA1: Self = Self(1 << 9);
+  /// DMA 2 finished.
+  pub const DMA2: Self = Self(1 << 10);
+  /// DMA 3 finished.
+  pub const DMA3: Self = Self(1 << 11);
+  /// The keypad condition in [`KEYCNT_ADDR`] was met.
+  pub const KEYPAD: Self = Self(1 << 12);
+  /// The Game Pak was removed.
+  pub const GAMEPAK: Self = Self(1 << 13);
+  /// All sources.
+  pub const ALL: Self = Self(0x3FFF);
+
+  /// The bit for a given timer.
+  ///
+  /// ## Panics
+  /// `i` must be < 4.
+  pub const fn timer(i: usize) -> Self {
+    Self(Self::TIMER0.0 << const_bound_check(i, TIMER_COUNT))
+  }
+
+  /// The bit for a given DMA unit.
+  ///
+  /// ## Panics
+  /// `i` must be < 4.
+  pub const fn dma(i: usize) -> Self {
+    Self(Self::DMA0.0 << const_bound_check(i, DMA_COUNT))
+  }
+
+  /// If no sources are in the set.
+  pub const fn is_empty(self) -> bool {
+    self.0 == 0
+  }
+
+  /// If all sources of `other` are in this set.
+  pub const fn contains(self, other: Self) -> bool {
+    (self.0 & other.0) == other.0
+  }
+
+  /// If any sources of `other` are in this set.
+  pub const fn intersects(self, other: Self) -> bool {
+    (self.0 & other.0) != 0
+  }
+
+  /// The sources found in either set.
+  pub const fn union(self, other: Self) -> Self {
+    Self(self.0 | other.0)
+  }
+
+  /// The sources found in both sets.
+  pub const fn intersection(self, other: Self) -> Self {
+    Self(self.0 & other.0)
+  }
+
+  /// The sources of this set that aren't in `other`.
+  pub const fn difference(self, other: Self) -> Self {
+    Self(self.0 & !other.0)
+  }
+
+  /// The highest priority source in the set, or `None` if the set is empty.
+  pub const fn first(self) -> Option<Self> {
+    if self.0 == 0 {
+      None
+    } else {
+      Some(Self(self.0 & self.0.wrapping_neg()))
+    }
+  }
+
+  /// Iterates over each source in the set, in priority order.
+  pub const fn iter(self) -> IrqBitsIter {
+    IrqBitsIter(self)
+  }
+}
+
+impl IntoIterator for IrqBits {
+  type Item = IrqBits;
+  type IntoIter = IrqBitsIter;
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+/// An iterator over the sources of an [`IrqBits`], in priority order.
+///
+/// Each item has exactly one bit set.
+#[derive(Debug, Clone)]
+pub struct IrqBitsIter(IrqBits);
+impl Iterator for IrqBitsIter {
+  type Item = IrqBits;
+  fn next(&mut self) -> Option<IrqBits> {
+    let bit = self.0.first()?;
+    self.0 = self.0.difference(bit);
+    Some(bit)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_irq_bits_iter_order() {
+    let pending = IrqBits::KEYPAD
+      .union(IrqBits::timer(2))
+      .union(IrqBits::VBLANK);
+    let mut iter = pending.iter();
+    assert_eq!(iter.next(), Some(IrqBits::VBLANK));
+    assert_eq!(iter.next(), Some(IrqBits::TIMER2));
+    assert_eq!(iter.next(), Some(IrqBits::KEYPAD));
+    assert_eq!(iter.next(), None);
+    assert_eq!(pending.first(), Some(IrqBits::VBLANK));
+  }
+
+  #[test]
+  fn test_irq_bits_iter_empty() {
+    assert_eq!(IrqBits::NONE.iter().next(), None);
+    assert_eq!(IrqBits::NONE.first(), None);
+  }
+
+  #[test]
+  fn test_irq_bits_iter_all() {
+    assert_eq!(IrqBits::ALL.iter().count(), 14);
+    assert!(IrqBits::ALL
+      .into_iter()
+      .zip(IrqBits::ALL.into_iter().skip(1))
+      .all(|(a, b)| a.to_u16() < b.to_u16()));
+  }
+}
@@ -12,6 +12,8 @@
 //! * **Bus Size:** 32-bit
 //! * **Read/Write:** 8/16/32
 
+use super::*;
+
 /// Base Address of `IWRAM`
 pub const IWRAM_START_ADDR: usize = 0x0300_0000;
 
@@ -21,6 +23,16 @@ pub const IWRAM_ENTRY_SIZE: usize = 1;
 /// There is 32kb of memory in `IWRAM`, though parts of it are reserved.
 pub const IWRAM_COUNT: usize = 32 * 1024;
 
+/// Access info for `IWRAM`.
+pub const IWRAM_REGION_INFO: RegionInfo = RegionInfo {
+  bus_width: AccessWidth::Bits32,
+  reads: AccessWidths::ALL,
+  writes: AccessWidths::ALL,
+  wait_states: 0,
+  dma_source: true,
+  dma_destination: true,
+};
+
 /// All `IWRAM` above this point is reserved.
 pub const IWRAM_RESERVED: usize = 0x0300_7F00;
 
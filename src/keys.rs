@@ -0,0 +1,252 @@
+//! Keypad values.
+//!
+//! [`KEYINPUT_ADDR`] uses a "low active" convention, where a key's bit is 0
+//! when it's pressed. [`Keys`] flips that around, so that a bit is 1 when the
+//! key is pressed, which is much easier to work with.
+//!
+//! [`KEYCNT_ADDR`] uses the same key bits, but they're "high active" to begin
+//! with, so a [`KeyInterruptControl`] can use a [`Keys`] value directly.
+
+use super::*;
+
+/// The keys that are pressed.
+///
+/// * 0: A
+/// * 1: B
+/// * 2: Select
+/// * 3: Start
+/// * 4: Right
+/// * 5: Left
+/// * 6: Up
+/// * 7: Down
+/// * 8: R
+/// * 9: L
+///
+/// The value is "high active", use [`from_keyinput`](Self::from_keyinput) to
+/// convert a value read from [`KEYINPUT_ADDR`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Keys(u16);
+impl Keys {
+  u16_newtype_basics!();
+
+  /// Info about each field.
+  pub const FIELDS: &'static [FieldInfo] = &[
+    FieldInfo::new("a", 0, 1),
+    FieldInfo::new("b", 1, 1),
+    FieldInfo::new("select", 2, 1),
+    FieldInfo::new("start", 3, 1),
+    FieldInfo::new("right", 4, 1),
+    FieldInfo::new("left", 5, 1),
+    FieldInfo::new("up", 6, 1),
+    FieldInfo::new("down", 7, 1),
+    FieldInfo::new("r", 8, 1),
+    FieldInfo::new("l", 9, 1),
+  ];
+
+  /// Info about each field of the "low active" [`KEYINPUT_ADDR`] value, where
+  /// each bit is 1 when the key is *released*.
+  pub const KEYINPUT_FIELDS: &'static [FieldInfo] = &[
+    FieldInfo::new("a_released", 0, 1),
+    FieldInfo::new("b_released", 1, 1),
+    FieldInfo::new("select_released", 2, 1),
+    FieldInfo::new("start_released", 3, 1),
+    FieldInfo::new("right_released", 4, 1),
+    FieldInfo::new("left_released", 5, 1),
+    FieldInfo::new("up_released", 6, 1),
+    FieldInfo::new("down_released", 7, 1),
+    FieldInfo::new("r_released", 8, 1),
+    FieldInfo::new("l_released", 9, 1),
+  ];
+
+  /// The bits used by keys.
+  const MASK: u16 = 0x03FF;
+
+  /// Converts a "low active" value read from [`KEYINPUT_ADDR`].
+  pub const fn from_keyinput(keyinput: u16) -> Self {
+    Self(!keyinput & Self::MASK)
+  }
+
+  /// Converts into the "low active" format of [`KEYINPUT_ADDR`].
+  pub const fn to_keyinput(self) -> u16 {
+    !self.0 & Self::MASK
+  }
+
+  u16_bool_field!(
+    /// If A is pressed.
+    0,
+    a,
+    with_a
+  );
+  u16_bool_field!(
+    /// If B is pressed.
+    1,
+    b,
+    with_b
+  );
+  u16_bool_field!(
+    /// If Select is pressed.
+    2,
+    select,
+    with_select
+  );
+  u16_bool_field!(
+    /// If Start is pressed.
+    3,
+    start,
+    with_start
+  );
+  u16_bool_field!(
+    /// If Right is pressed.
+    4,
+    right,
+    with_right
+  );
+  u16_bool_field!(
+    /// If Left is pressed.
+    5,
+    left,
+    with_left
+  );
+  u16_bool_field!(
+    /// If Up is pressed.
+    6,
+    up,
+    with_up
+  );
+  u16_bool_field!(
+    /// If Down is pressed.
+    7,
+    down,
+    with_down
+  );
+  u16_bool_field!(
+    /// If R is pressed.
+    8,
+    r,
+    with_r
+  );
+  u16_bool_field!(
+    /// If L is pressed.
+    9,
+    l,
+    with_l
+  );
+
+  /// The keys pressed now that weren't pressed in the `previous` sample.
+  pub const fn pressed(self, previous: Self) -> Self {
+    Self(self.0 & !previous.0)
+  }
+
+  /// The keys pressed in the `previous` sample that aren't pressed now.
+  pub const fn released(self, previous: Self) -> Self {
+    Self(previous.0 & !self.0)
+  }
+
+  /// The keys pressed both now and in the `previous` sample.
+  pub const fn held(self, previous: Self) -> Self {
+    Self(self.0 & previous.0)
+  }
+
+  /// The horizontal direction of the d-pad.
+  ///
+  /// This is -1 for left, +1 for right, and 0 for neither or both.
+  pub const fn x_axis(self) -> i32 {
+    self.right() as i32 - self.left() as i32
+  }
+
+  /// The vertical direction of the d-pad.
+  ///
+  /// This is -1 for up, +1 for down, and 0 for neither or both. This matches
+  /// the screen, where `y` increases going down.
+  pub const fn y_axis(self) -> i32 {
+    self.down() as i32 - self.up() as i32
+  }
+}
+
+/// A value for [`KEYCNT_ADDR`].
+///
+/// * 0-9: The keys to watch, as with [`Keys`]
+/// * 14: Interrupt enabled
+/// * 15: Condition (0 for any watched key, 1 for all watched keys)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct KeyInterruptControl(u16);
+impl KeyInterruptControl {
+  u16_newtype_basics!();
+
+  /// Info about each field.
+  pub const FIELDS: &'static [FieldInfo] = &[
+    FieldInfo::new("keys", 0, 10),
+    FieldInfo::new("irq", 14, 1),
+    FieldInfo::new("and_condition", 15, 1),
+  ];
+
+  /// The keys that are watched.
+  pub const fn keys(self) -> Keys {
+    Keys(self.0 & Keys::MASK)
+  }
+
+  /// Replaces the [`keys`](Self::keys) value.
+  pub const fn with_keys(self, keys: Keys) -> Self {
+    Self((self.0 & !Keys::MASK) | (keys.0 & Keys::MASK))
+  }
+
+  u16_bool_field!(
+    /// If the keypad interrupt is enabled.
+    14,
+    irq,
+    with_irq
+  );
+  u16_bool_field!(
+    /// If all watched keys must be pressed (otherwise any watched key).
+    15,
+    and_condition,
+    with_and_condition
+  );
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_from_keyinput() {
+    assert_eq!(Keys::from_keyinput(0x03FF), Keys::new());
+    assert_eq!(Keys::from_keyinput(0x03FE), Keys::new().with_a(true));
+    // the unused high bits are ignored
+    assert_eq!(Keys::from_keyinput(0xFFFF), Keys::new());
+    assert_eq!(Keys::from_keyinput(0x0000).to_u16(), 0x03FF);
+    assert_eq!(Keys::new().with_l(true).to_keyinput(), 0x01FF);
+  }
+
+  #[test]
+  fn test_pressed_released() {
+    let previous = Keys::from_keyinput(0x03FE); // A
+    let current = Keys::from_keyinput(0x03FC); // A and B
+    assert_eq!(current.pressed(previous), Keys::new().with_b(true));
+    assert_eq!(current.released(previous), Keys::new());
+    assert_eq!(current.held(previous), Keys::new().with_a(true));
+
+    let next = Keys::from_keyinput(0x03FD); // B
+    assert_eq!(next.pressed(current), Keys::new());
+    assert_eq!(next.released(current), Keys::new().with_a(true));
+    assert_eq!(next.held(current), Keys::new().with_b(true));
+
+    let none = Keys::from_keyinput(0x03FF);
+    assert_eq!(none.pressed(none), Keys::new());
+    assert_eq!(none.released(next), Keys::new().with_b(true));
+  }
+
+  #[test]
+  fn test_axes() {
+    let none = Keys::from_keyinput(0x03FF);
+    assert_eq!((none.x_axis(), none.y_axis()), (0, 0));
+    let right_up = Keys::from_keyinput(0x03AF);
+    assert_eq!((right_up.x_axis(), right_up.y_axis()), (1, -1));
+    let left_down = Keys::from_keyinput(0x035F);
+    assert_eq!((left_down.x_axis(), left_down.y_axis()), (-1, 1));
+    let all = Keys::from_keyinput(0x0000);
+    assert_eq!((all.x_axis(), all.y_axis()), (0, 0));
+  }
+}
@@ -10,7 +10,17 @@
 //!
 //! All info is exported at the crate root, but the various modules provide some
 //! notes about the general usage of that portion of memory.
+//!
+//! ## Features
+//!
+//! * `std`: Adds the `MockBus` type, which models the GBA's memory map with
+//!   host memory so that code can be tested without an emulator. Also adds the
+//!   `export` module, which generates linker scripts and other files from the
+//!   values in this crate.
+//! * `volatile`: Adds the `VolAddress` type, as well as the `vol` module of
+//!   typed IO register addresses.
 
+#[allow(clippy::no_effect)]
 const fn const_bound_check(index: usize, bound: usize) -> usize {
   const ARRAY: [&str; 1] = ["index out of bounds"];
   ARRAY[(index >= bound) as usize];
@@ -48,6 +58,72 @@ macro_rules! const_assert {
   };
 }
 
+/// Declares a `bool` getter and a `with_` setter for a single bit of a `u16`
+/// newtype.
+macro_rules! u16_bool_field {
+  ($(#[$meta:meta])* $bit:literal, $get:ident, $with:ident) => {
+    $(#[$meta])*
+    pub const fn $get(self) -> bool {
+      (self.0 & (1 << $bit)) != 0
+    }
+
+    #[doc = concat!(
+      "Replaces the [`", stringify!($get), "`](Self::", stringify!($get), ") bit."
+    )]
+    pub const fn $with(self, $get: bool) -> Self {
+      Self((self.0 & !(1 << $bit)) | (($get as u16) << $bit))
+    }
+  };
+}
+
+/// Declares an integer getter and a `with_` setter for an inclusive span of
+/// bits of a `u16` newtype.
+///
+/// The setter discards any bits of the new value that don't fit.
+macro_rules! u16_int_field {
+  ($(#[$meta:meta])* $low:literal - $high:literal, $get:ident, $with:ident) => {
+    $(#[$meta])*
+    pub const fn $get(self) -> u16 {
+      const MASK: u16 = (((1_u32 << ($high - $low + 1)) - 1) << $low) as u16;
+      (self.0 & MASK) >> $low
+    }
+
+    #[doc = concat!(
+      "Replaces the [`", stringify!($get), "`](Self::", stringify!($get), ") value."
+    )]
+    pub const fn $with(self, $get: u16) -> Self {
+      const MASK: u16 = (((1_u32 << ($high - $low + 1)) - 1) << $low) as u16;
+      Self((self.0 & !MASK) | (($get << $low) & MASK))
+    }
+  };
+}
+
+/// Declares the `new`/`from_u16`/`to_u16` functions of a `u16` newtype.
+macro_rules! u16_newtype_basics {
+  () => {
+    /// A value with all bits clear.
+    pub const fn new() -> Self {
+      Self(0)
+    }
+
+    /// Wraps a raw `u16` value.
+    pub const fn from_u16(u: u16) -> Self {
+      Self(u)
+    }
+
+    /// Unwrap the value into a `u16`.
+    pub const fn to_u16(self) -> u16 {
+      self.0
+    }
+  };
+}
+
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod bios;
+pub use bios::*;
+
 pub mod ewram;
 pub use ewram::*;
 
@@ -71,3 +147,60 @@ pub use rom::*;
 
 pub mod sram;
 pub use sram::*;
+
+pub mod region;
+pub use region::*;
+
+pub mod wait_control;
+pub use wait_control::*;
+
+pub mod cycles;
+pub use cycles::*;
+
+pub mod display;
+pub use display::*;
+
+pub mod dma;
+pub use dma::*;
+
+pub mod timer;
+pub use timer::*;
+
+pub mod irq;
+pub use irq::*;
+
+pub mod keys;
+pub use keys::*;
+
+pub mod color;
+pub use color::*;
+
+pub mod obj;
+pub use obj::*;
+
+pub mod screen_entry;
+pub use screen_entry::*;
+
+pub mod shadow_oam;
+pub use shadow_oam::*;
+
+pub mod fixed;
+pub use fixed::*;
+
+pub mod affine;
+pub use affine::*;
+
+#[cfg(feature = "std")]
+pub mod export;
+#[cfg(feature = "std")]
+pub use export::*;
+
+#[cfg(feature = "std")]
+pub mod mock_bus;
+#[cfg(feature = "std")]
+pub use mock_bus::*;
+
+#[cfg(feature = "volatile")]
+pub mod volatile;
+#[cfg(feature = "volatile")]
+pub use volatile::*;
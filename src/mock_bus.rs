@@ -0,0 +1,358 @@
+//! A host memory model of the GBA's address space.
+//!
+//! This module is only available with the `std` feature.
+//!
+//! The [`MockBus`] lets code that works with the addresses of this crate be run
+//! under a normal `cargo test`, and then you can assert on what was written.
+//!
+//! * Each memory region is backed by a host buffer of the correct size.
+//! * Addresses within mirrors are translated to the base region.
+//! * 16-bit and 32-bit accesses are forced to alignment, like on the GBA.
+//! * Reads from unmapped addresses, or past the end of the loaded ROM, give 0.
+//! * Writes to unmapped addresses, the `BIOS`, and `ROM` are ignored.
+//!
+//! The bus also reproduces the access width quirks of each region, so that
+//! tests will catch the same mistakes that real hardware punishes:
+//! * 8-bit writes to `PALRAM` write the byte to both halves of the 16-bit
+//!   value.
+//! * 8-bit writes to the background portion of `VRAM` do the same, and 8-bit
+//!   writes to the object portion of `VRAM` are ignored. The boundary depends
+//!   on the video mode set in [`DISPCNT_ADDR`].
+//! * 8-bit writes to `OAM` are ignored.
+//! * 16-bit and 32-bit reads of `SRAM` give the byte at the target address
+//!   mirrored to the full width.
+//! * 16-bit and 32-bit writes to `SRAM` store a single byte, which is the data
+//!   rotated right by `address*8`.
+//! * `SRAM` accesses aren't forced to alignment.
+//!
+//! The IO registers are modeled as plain memory, they don't *do* anything when
+//! written to.
+
+use super::*;
+use std::{vec, vec::Vec};
+
+/// A model of the GBA's memory map, backed by host memory.
+///
+/// The initial state of all memory is zeroed, except that
+/// [`KEYINPUT_ADDR`] reads as "no keys pressed".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockBus {
+  bios: Vec<u8>,
+  ewram: Vec<u8>,
+  iwram: Vec<u8>,
+  io: Vec<u8>,
+  palram: Vec<u8>,
+  vram: Vec<u8>,
+  oam: Vec<u8>,
+  rom: Vec<u8>,
+  sram: Vec<u8>,
+}
+impl Default for MockBus {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+impl MockBus {
+  /// Makes a new bus with no ROM loaded.
+  pub fn new() -> Self {
+    let mut bus = Self {
+      bios: vec![0; BIOS_COUNT],
+      ewram: vec![0; EWRAM_COUNT],
+      iwram: vec![0; IWRAM_COUNT],
+      io: vec![0; IO_COUNT],
+      palram: vec![0; PALRAM_COUNT],
+      vram: vec![0; VRAM_COUNT],
+      oam: vec![0; OAM_COUNT],
+      rom: Vec::new(),
+      sram: vec![0; SRAM_COUNT],
+    };
+    bus.write_u16(KEYINPUT_ADDR, Keys::new().to_keyinput());
+    bus
+  }
+
+  /// Loads the given ROM into the bus.
+  ///
+  /// ## Panics
+  /// The ROM can't be more than 32MB.
+  pub fn with_rom(self, rom: Vec<u8>) -> Self {
+    assert!(rom.len() <= ROM_MAX_COUNT, "ROM is larger than 32MB");
+    Self { rom, ..self }
+  }
+
+  /// Loads the given BIOS image into the bus.
+  ///
+  /// ## Panics
+  /// The BIOS image can't be more than 16kb.
+  pub fn with_bios(self, bios: &[u8]) -> Self {
+    assert!(bios.len() <= BIOS_COUNT, "BIOS is larger than 16kb");
+    let mut bus = self;
+    bus.bios[..bios.len()].copy_from_slice(bios);
+    bus
+  }
+
+  fn region(&self, region: MemoryRegion) -> &[u8] {
+    match region {
+      MemoryRegion::Bios => &self.bios,
+      MemoryRegion::Ewram => &self.ewram,
+      MemoryRegion::Iwram => &self.iwram,
+      MemoryRegion::Io => &self.io,
+      MemoryRegion::Palram => &self.palram,
+      MemoryRegion::Vram => &self.vram,
+      MemoryRegion::Oam => &self.oam,
+      MemoryRegion::RomWait0
+      | MemoryRegion::RomWait1
+      | MemoryRegion::RomWait2 => &self.rom,
+      MemoryRegion::Sram => &self.sram,
+    }
+  }
+
+  fn region_mut(&mut self, region: MemoryRegion) -> Option<&mut [u8]> {
+    match region {
+      MemoryRegion::Bios
+      | MemoryRegion::RomWait0
+      | MemoryRegion::RomWait1
+      | MemoryRegion::RomWait2 => None,
+      MemoryRegion::Ewram => Some(&mut self.ewram),
+      MemoryRegion::Iwram => Some(&mut self.iwram),
+      MemoryRegion::Io => Some(&mut self.io),
+      MemoryRegion::Palram => Some(&mut self.palram),
+      MemoryRegion::Vram => Some(&mut self.vram),
+      MemoryRegion::Oam => Some(&mut self.oam),
+      MemoryRegion::Sram => Some(&mut self.sram),
+    }
+  }
+
+  fn read_bytes<const N: usize>(&self, addr: usize) -> [u8; N] {
+    let mut out = [0; N];
+    if let Some((region, offset)) = classify(addr & !(N - 1)) {
+      if let Some(bytes) = self.region(region).get(offset..offset + N) {
+        out.copy_from_slice(bytes);
+      }
+    }
+    out
+  }
+
+  fn write_bytes<const N: usize>(&mut self, addr: usize, data: [u8; N]) {
+    if let Some((region, offset)) = classify(addr & !(N - 1)) {
+      if let Some(mem) = self.region_mut(region) {
+        mem[offset..offset + N].copy_from_slice(&data);
+      }
+    }
+  }
+
+  /// The first `VRAM` offset that's object tile data in the current video
+  /// mode.
+  fn vram_obj_offset(&self) -> usize {
+    let dispcnt = DisplayControl::from_u16(self.read_u16(DISPCNT_ADDR));
+    if dispcnt.video_mode() >= 3 {
+      CHARBLOCK_OBJ_BASE_ADDR - VRAM_BASE_ADDR + CHARBLOCK_SIZE
+    } else {
+      CHARBLOCK_OBJ_BASE_ADDR - VRAM_BASE_ADDR
+    }
+  }
+
+  /// Reads a `u8` from the address.
+  pub fn read_u8(&self, addr: usize) -> u8 {
+    u8::from_le_bytes(self.read_bytes(addr))
+  }
+
+  /// Reads a `u16` from the address.
+  pub fn read_u16(&self, addr: usize) -> u16 {
+    match classify(addr) {
+      Some((MemoryRegion::Sram, _)) => u16::from(self.read_u8(addr)) * 0x0101,
+      _ => u16::from_le_bytes(self.read_bytes(addr)),
+    }
+  }
+
+  /// Reads a `u32` from the address.
+  pub fn read_u32(&self, addr: usize) -> u32 {
+    match classify(addr) {
+      Some((MemoryRegion::Sram, _)) => {
+        u32::from(self.read_u8(addr)) * 0x0101_0101
+      }
+      _ => u32::from_le_bytes(self.read_bytes(addr)),
+    }
+  }
+
+  /// Writes a `u8` to the address.
+  pub fn write_u8(&mut self, addr: usize, u: u8) {
+    match classify(addr) {
+      Some((MemoryRegion::Palram, _)) => self.write_bytes(addr, [u, u]),
+      Some((MemoryRegion::Vram, offset)) => {
+        if offset < self.vram_obj_offset() {
+          self.write_bytes(addr, [u, u])
+        }
+      }
+      Some((MemoryRegion::Oam, _)) => (),
+      _ => self.write_bytes(addr, [u]),
+    }
+  }
+
+  /// Writes a `u16` to the address.
+  pub fn write_u16(&mut self, addr: usize, u: u16) {
+    match classify(addr) {
+      Some((MemoryRegion::Sram, _)) => {
+        self.write_u32(addr, u32::from(u) * 0x0001_0001)
+      }
+      _ => self.write_bytes(addr, u.to_le_bytes()),
+    }
+  }
+
+  /// Writes a `u32` to the address.
+  pub fn write_u32(&mut self, addr: usize, u: u32) {
+    match classify(addr) {
+      Some((MemoryRegion::Sram, _)) => {
+        self.write_u8(addr, u.rotate_right(((addr & 3) as u32) * 8) as u8)
+      }
+      _ => self.write_bytes(addr, u.to_le_bytes()),
+    }
+  }
+
+  /// The full contents of the memory region that contains the address.
+  ///
+  /// The offset of an address within these bytes is its [`canonical_addr`]
+  /// minus the [`base_addr`](MemoryRegion::base_addr) of its region (using
+  /// [`ROM_WAIT0_BASE_ADDR`] for all of the `ROM` mirrors).
+  ///
+  /// Returns `None` if the address isn't mapped to any memory.
+  pub fn region_bytes(&self, addr: usize) -> Option<&[u8]> {
+    region_of(addr).map(|region| self.region(region))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_ram_mirrors() {
+    let mut bus = MockBus::new();
+    let mirrors = [
+      (0x0204_0004, 0x0200_0004),
+      (0x0300_8004, 0x0300_0004),
+      (0x0500_0404, 0x0500_0004),
+      (0x0601_8004, 0x0601_0004),
+      (0x0602_0004, 0x0600_0004),
+      (0x0700_0404, 0x0700_0004),
+    ];
+    for (i, &(mirror, base)) in mirrors.iter().enumerate() {
+      let u = 0x1234_5678 + i as u32;
+      bus.write_u32(mirror, u);
+      assert_eq!(bus.read_u32(base), u, "{:#X}", mirror);
+      assert_eq!(canonical_addr(mirror), Some(base));
+    }
+  }
+
+  #[test]
+  fn test_sram_mirrors() {
+    let mut bus = MockBus::new();
+    bus.write_u8(0x0E01_0005, 0xAB);
+    assert_eq!(bus.read_u8(0x0E00_0005), 0xAB);
+    assert_eq!(bus.read_u8(0x0F00_0005), 0xAB);
+  }
+
+  #[test]
+  fn test_rom_mirrors() {
+    let bus = MockBus::new().with_rom(vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    for &base in &[
+      ROM_WAIT0_BASE_ADDR,
+      ROM_WAIT1_BASE_ADDR,
+      ROM_WAIT2_BASE_ADDR,
+    ] {
+      assert_eq!(bus.read_u32(base), 0x0403_0201);
+      assert_eq!(bus.read_u16(base + 6), 0x0807);
+    }
+  }
+
+  #[test]
+  fn test_rom_past_end_reads_zero() {
+    let bus = MockBus::new().with_rom(vec![0xFF; 6]);
+    assert_eq!(bus.read_u16(ROM_WAIT0_BASE_ADDR + 4), 0xFFFF);
+    assert_eq!(bus.read_u32(ROM_WAIT0_BASE_ADDR + 4), 0);
+    assert_eq!(bus.read_u8(ROM_WAIT0_BASE_ADDR + 6), 0);
+    assert_eq!(bus.read_u8(ROM_WAIT0_BASE_ADDR + 0x100), 0);
+  }
+
+  #[test]
+  fn test_bios_and_rom_writes_ignored() {
+    let mut bus = MockBus::new().with_bios(&[0x11; 4]).with_rom(vec![0x22; 4]);
+    bus.write_u32(BIOS_START_ADDR, 0);
+    bus.write_u16(BIOS_START_ADDR, 0);
+    bus.write_u8(BIOS_START_ADDR, 0);
+    assert_eq!(bus.read_u32(BIOS_START_ADDR), 0x1111_1111);
+    bus.write_u32(ROM_WAIT0_BASE_ADDR, 0);
+    bus.write_u16(ROM_WAIT1_BASE_ADDR, 0);
+    bus.write_u8(ROM_WAIT2_BASE_ADDR, 0);
+    assert_eq!(bus.read_u32(ROM_WAIT0_BASE_ADDR), 0x2222_2222);
+  }
+
+  #[test]
+  fn test_unmapped() {
+    let mut bus = MockBus::new();
+    bus.write_u32(0x0100_0000, 0xFFFF_FFFF);
+    assert_eq!(bus.read_u32(0x0100_0000), 0);
+    assert_eq!(bus.read_u32(IO_START_ADDR + IO_COUNT), 0);
+    assert_eq!(bus.region_bytes(0x1000_0000), None);
+  }
+
+  #[test]
+  fn test_palram_byte_write() {
+    let mut bus = MockBus::new();
+    bus.write_u8(PALRAM_START_ADDR + 3, 0xAB);
+    assert_eq!(bus.read_u16(PALRAM_START_ADDR + 2), 0xABAB);
+  }
+
+  #[test]
+  fn test_vram_byte_write_split() {
+    let mut bus = MockBus::new();
+    // modes 0-2: object tiles start at 0x10000
+    bus.write_u8(VRAM_BASE_ADDR + 0xFFFF, 0xAB);
+    bus.write_u8(VRAM_BASE_ADDR + 0x1_0000, 0xCD);
+    bus.write_u8(VRAM_BASE_ADDR + 0x1_4000, 0xEF);
+    assert_eq!(bus.read_u16(VRAM_BASE_ADDR + 0xFFFE), 0xABAB);
+    assert_eq!(bus.read_u16(VRAM_BASE_ADDR + 0x1_0000), 0);
+    assert_eq!(bus.read_u16(VRAM_BASE_ADDR + 0x1_4000), 0);
+    // modes 3-5: the bitmap extends the split to 0x14000
+    bus.write_u16(
+      DISPCNT_ADDR,
+      DisplayControl::new().with_video_mode(3).to_u16(),
+    );
+    bus.write_u8(VRAM_BASE_ADDR + 0x1_0000, 0xCD);
+    bus.write_u8(VRAM_BASE_ADDR + 0x1_4000, 0xEF);
+    assert_eq!(bus.read_u16(VRAM_BASE_ADDR + 0x1_0000), 0xCDCD);
+    assert_eq!(bus.read_u16(VRAM_BASE_ADDR + 0x1_4000), 0);
+  }
+
+  #[test]
+  fn test_oam_byte_write_ignored() {
+    let mut bus = MockBus::new();
+    bus.write_u8(OAM_START_ADDR, 0xAB);
+    assert_eq!(bus.read_u16(OAM_START_ADDR), 0);
+    bus.write_u16(OAM_START_ADDR, 0xABCD);
+    assert_eq!(bus.read_u16(OAM_START_ADDR), 0xABCD);
+  }
+
+  #[test]
+  fn test_sram_wide_reads() {
+    let mut bus = MockBus::new();
+    bus.write_u8(SRAM_BASE_ADDR + 1, 0x33);
+    assert_eq!(bus.read_u16(SRAM_BASE_ADDR + 1), 0x3333);
+    assert_eq!(bus.read_u32(SRAM_BASE_ADDR + 1), 0x3333_3333);
+    assert_eq!(bus.read_u32(SRAM_BASE_ADDR), 0);
+  }
+
+  #[test]
+  fn test_sram_wide_writes() {
+    let mut bus = MockBus::new();
+    for addr in 0..4 {
+      bus.write_u32(SRAM_BASE_ADDR + addr, 0x4433_2211);
+    }
+    bus.write_u16(SRAM_BASE_ADDR + 5, 0x6655);
+    assert_eq!(bus.read_u8(SRAM_BASE_ADDR), 0x11);
+    assert_eq!(bus.read_u8(SRAM_BASE_ADDR + 1), 0x22);
+    assert_eq!(bus.read_u8(SRAM_BASE_ADDR + 2), 0x33);
+    assert_eq!(bus.read_u8(SRAM_BASE_ADDR + 3), 0x44);
+    assert_eq!(bus.read_u8(SRAM_BASE_ADDR + 4), 0);
+    assert_eq!(bus.read_u8(SRAM_BASE_ADDR + 5), 0x66);
+  }
+}
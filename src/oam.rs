@@ -6,7 +6,8 @@
 //! * Object Attributes:
 //!   * You get 128 object entries.
 //!   * Each complete entry is three 16-bit values. These pseudo-fields are
-//!     referred to as "attr0", "attr1", and "attr2".
+//!     referred to as "attr0", "attr1", and "attr2". See [`ObjAttr`] for
+//!     the values.
 //!   * There's also a 16-bit span that's *not* part of the object attributes
 //!     after each set of attributes, before the next attribute entry begins.
 //! * Affine Parameters
@@ -18,18 +19,39 @@
 //!     of `OAM`, then `pb`, `pc`, and `pd` are strided 8 bytes at a time from
 //!     there, then the next affine parameter entry begins 8 bytes after that.
 //!
-//! You **cannot** validly write a single byte to `OAM`. If you do so, the
-//! 8-bits are written to both the upper and lower 8-bits of the given 16-bit
-//! segment of memory. This isn't a big deal, since all the pseudo-fields of the
+//! You **cannot** validly write a single byte to `OAM`. If you do so, the write
+//! is simply ignored. This isn't a big deal, since all the pseudo-fields of the
 //! stuff in `OAM` uses 16-bits anyway.
 //!
+//! To avoid tearing, most programs edit a [`ShadowOam`] during the frame and
+//! then copy it into `OAM` during VBlank.
+//!
 //! * **Size:** 1kb
 //! * **Wait states:** 0
 //! * **Bus Size:** 32-bit
-//! * **Read/Write:** 16/32
+//! * **Reads:** 8/16/32
+//! * **Writes:** 16/32
 
 use super::*;
 
+/// Base Address of `OAM`.
+pub const OAM_START_ADDR: usize = 0x0700_0000;
+
+/// There is 1kb of `OAM`.
+///
+/// This is mirrored across the rest of the `0x07xx_xxxx` addresses.
+pub const OAM_COUNT: usize = 1024;
+
+/// Access info for `OAM`.
+pub const OAM_REGION_INFO: RegionInfo = RegionInfo {
+  bus_width: AccessWidth::Bits32,
+  reads: AccessWidths::ALL,
+  writes: AccessWidths::BITS16_32,
+  wait_states: 0,
+  dma_source: true,
+  dma_destination: true,
+};
+
 /// Base address of the object attribute `attr0` fields.
 pub const OBJ_ATTR0_BASE_ADDR: usize = 0x0700_0000;
 
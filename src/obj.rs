@@ -0,0 +1,365 @@
+//! Object attribute values.
+//!
+//! Each of the object attribute entries in `OAM` is three `u16` values, see
+//! [`index_obj_attr`] for their addresses. The types here give names to the
+//! bits of each value, and [`ObjAttr`] groups all three together.
+//!
+//! An object's size in pixels depends on both its shape (in `attr0`) and its
+//! size (in `attr1`):
+//!
+//! | Size | Square | Horizontal | Vertical |
+//! |:----:|:------:|:----------:|:--------:|
+//! | 0    | 8x8    | 16x8       | 8x16     |
+//! | 1    | 16x16  | 32x8       | 8x32     |
+//! | 2    | 32x32  | 32x16      | 16x32    |
+//! | 3    | 64x64  | 64x32      | 32x64    |
+
+use super::*;
+
+/// The `(width, height)` in pixels of each object shape and size.
+///
+/// This is indexed as `OBJ_DIMENSIONS[shape][size]`.
+pub const OBJ_DIMENSIONS: [[(usize, usize); 4]; 3] = [
+  [(8, 8), (16, 16), (32, 32), (64, 64)],
+  [(16, 8), (32, 8), (32, 16), (64, 32)],
+  [(8, 16), (8, 32), (16, 32), (32, 64)],
+];
+
+/// The `(width, height)` in pixels of an object with the given shape and
+/// size.
+///
+/// Returns `None` if the shape is 3, which is prohibited.
+///
+/// ## Panics
+/// `size` must be < 4.
+pub const fn obj_dimensions(shape: u16, size: u16) -> Option<(usize, usize)> {
+  let size = const_bound_check(size as usize, 4);
+  if (shape as usize) < OBJ_DIMENSIONS.len() {
+    Some(OBJ_DIMENSIONS[shape as usize][size])
+  } else {
+    None
+  }
+}
+
+/// How an object is displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u16)]
+pub enum ObjDisplayMode {
+  /// Displayed normally.
+  Normal = 0,
+  /// Displayed using affine parameters.
+  Affine = 1,
+  /// Not displayed.
+  Disabled = 2,
+  /// Displayed using affine parameters, with the display area doubled in
+  /// each direction so that a rotated object isn't clipped.
+  AffineDoubleSize = 3,
+}
+
+/// The special effect applied to an object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u16)]
+pub enum ObjEffectMode {
+  /// No special effect.
+  Normal = 0,
+  /// The object is the first target of alpha blending.
+  SemiTransparent = 1,
+  /// The object isn't displayed, and instead forms the object window.
+  Window = 2,
+  /// Prohibited, don't use this.
+  Prohibited = 3,
+}
+
+/// An `attr0` value.
+///
+/// * 0-7: Y coordinate of the top of the object
+/// * 8-9: Display mode
+/// * 10-11: Effect mode
+/// * 12: Mosaic
+/// * 13: Use 8bpp tiles (otherwise 4bpp)
+/// * 14-15: Shape (square, horizontal, or vertical)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct ObjAttr0(u16);
+impl ObjAttr0 {
+  u16_newtype_basics!();
+
+  /// Info about each field.
+  pub const FIELDS: &'static [FieldInfo] = &[
+    FieldInfo::new("y", 0, 8),
+    FieldInfo::new("display_mode", 8, 2),
+    FieldInfo::new("effect_mode", 10, 2),
+    FieldInfo::new("mosaic", 12, 1),
+    FieldInfo::new("is_8bpp", 13, 1),
+    FieldInfo::new("shape", 14, 2),
+  ];
+
+  u16_int_field!(
+    /// The Y coordinate, 0 through 255. Values past the bottom of the screen
+    /// wrap around to the top.
+    0 - 7,
+    y,
+    with_y
+  );
+
+  /// How the object is displayed.
+  pub const fn display_mode(self) -> ObjDisplayMode {
+    match (self.0 >> 8) & 0b11 {
+      0 => ObjDisplayMode::Normal,
+      1 => ObjDisplayMode::Affine,
+      2 => ObjDisplayMode::Disabled,
+      _ => ObjDisplayMode::AffineDoubleSize,
+    }
+  }
+
+  /// Replaces the [`display_mode`](Self::display_mode) value.
+  pub const fn with_display_mode(self, display_mode: ObjDisplayMode) -> Self {
+    Self((self.0 & !(0b11 << 8)) | ((display_mode as u16) << 8))
+  }
+
+  /// The special effect applied to the object.
+  pub const fn effect_mode(self) -> ObjEffectMode {
+    match (self.0 >> 10) & 0b11 {
+      0 => ObjEffectMode::Normal,
+      1 => ObjEffectMode::SemiTransparent,
+      2 => ObjEffectMode::Window,
+      _ => ObjEffectMode::Prohibited,
+    }
+  }
+
+  /// Replaces the [`effect_mode`](Self::effect_mode) value.
+  pub const fn with_effect_mode(self, effect_mode: ObjEffectMode) -> Self {
+    Self((self.0 & !(0b11 << 10)) | ((effect_mode as u16) << 10))
+  }
+
+  u16_bool_field!(
+    /// If the mosaic effect is applied.
+    12,
+    mosaic,
+    with_mosaic
+  );
+  u16_bool_field!(
+    /// If the tiles are 8bpp (otherwise 4bpp).
+    13,
+    is_8bpp,
+    with_is_8bpp
+  );
+  u16_int_field!(
+    /// The shape, 0 (square), 1 (horizontal), or 2 (vertical).
+    14 - 15,
+    shape,
+    with_shape
+  );
+}
+
+/// An `attr1` value.
+///
+/// * 0-8: X coordinate of the left of the object
+/// * 9-13: Affine parameter index (affine display modes)
+/// * 12: Horizontal flip (non-affine display modes)
+/// * 13: Vertical flip (non-affine display modes)
+/// * 14-15: Size
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct ObjAttr1(u16);
+impl ObjAttr1 {
+  u16_newtype_basics!();
+
+  /// Info about each field.
+  pub const FIELDS: &'static [FieldInfo] = &[
+    FieldInfo::new("x", 0, 9),
+    FieldInfo::new("affine_index", 9, 5),
+    FieldInfo::new("size", 14, 2),
+  ];
+
+  u16_int_field!(
+    /// The X coordinate, 0 through 511. Values past the right of the screen
+    /// wrap around to the left.
+    0 - 8,
+    x,
+    with_x
+  );
+  u16_int_field!(
+    /// The affine parameter entry used, 0 through 31.
+    ///
+    /// Only used in the affine display modes, and overlaps the flip bits.
+    9 - 13,
+    affine_index,
+    with_affine_index
+  );
+  u16_bool_field!(
+    /// If the object is flipped horizontally.
+    ///
+    /// Only used in the non-affine display modes.
+    12,
+    hflip,
+    with_hflip
+  );
+  u16_bool_field!(
+    /// If the object is flipped vertically.
+    ///
+    /// Only used in the non-affine display modes.
+    13,
+    vflip,
+    with_vflip
+  );
+  u16_int_field!(
+    /// The size, 0 through 3. The meaning depends on the shape.
+    14 - 15,
+    size,
+    with_size
+  );
+}
+
+/// An `attr2` value.
+///
+/// * 0-9: Base tile index
+/// * 10-11: Priority (0 is drawn on top)
+/// * 12-15: Palbank (4bpp only)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct ObjAttr2(u16);
+impl ObjAttr2 {
+  u16_newtype_basics!();
+
+  /// Info about each field.
+  pub const FIELDS: &'static [FieldInfo] = &[
+    FieldInfo::new("tile", 0, 10),
+    FieldInfo::new("priority", 10, 2),
+    FieldInfo::new("palbank", 12, 4),
+  ];
+
+  u16_int_field!(
+    /// The base tile index, 0 through 1023.
+    ///
+    /// This always counts in 4bpp tiles, see [`index_obj_tile`].
+    0 - 9,
+    tile,
+    with_tile
+  );
+  u16_int_field!(
+    /// The draw priority, 0 through 3. Lower values are drawn on top.
+    10 - 11,
+    priority,
+    with_priority
+  );
+  u16_int_field!(
+    /// The palbank used by 4bpp tiles, 0 through 15.
+    12 - 15,
+    palbank,
+    with_palbank
+  );
+
+  /// The address of the [`tile`](Self::tile) in `VRAM`.
+  pub const fn tile_addr(self) -> usize {
+    index_obj_tile(self.tile() as usize)
+  }
+}
+
+/// All three attributes of an object attribute entry.
+///
+/// This has the same layout as the attributes in `OAM`, but without the
+/// affine parameter that follows them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct ObjAttr {
+  /// The `attr0` value.
+  pub attr0: ObjAttr0,
+  /// The `attr1` value.
+  pub attr1: ObjAttr1,
+  /// The `attr2` value.
+  pub attr2: ObjAttr2,
+}
+impl ObjAttr {
+  /// An entry that isn't displayed.
+  pub const HIDDEN: Self = Self {
+    attr0: ObjAttr0::new().with_display_mode(ObjDisplayMode::Disabled),
+    attr1: ObjAttr1::new(),
+    attr2: ObjAttr2::new(),
+  };
+
+  /// Makes an entry from the three raw values.
+  pub const fn from_u16s(u: [u16; 3]) -> Self {
+    Self {
+      attr0: ObjAttr0::from_u16(u[0]),
+      attr1: ObjAttr1::from_u16(u[1]),
+      attr2: ObjAttr2::from_u16(u[2]),
+    }
+  }
+
+  /// The three raw values of the entry.
+  pub const fn to_u16s(self) -> [u16; 3] {
+    [
+      self.attr0.to_u16(),
+      self.attr1.to_u16(),
+      self.attr2.to_u16(),
+    ]
+  }
+
+  /// The `(width, height)` of the object in pixels.
+  ///
+  /// Returns `None` if the shape is 3, which is prohibited.
+  pub const fn dimensions(self) -> Option<(usize, usize)> {
+    obj_dimensions(self.attr0.shape(), self.attr1.size())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_obj_dimensions() {
+    let expected = [
+      [(8, 8), (16, 16), (32, 32), (64, 64)],
+      [(16, 8), (32, 8), (32, 16), (64, 32)],
+      [(8, 16), (8, 32), (16, 32), (32, 64)],
+    ];
+    for (shape, sizes) in expected.iter().enumerate() {
+      for (size, &dimensions) in sizes.iter().enumerate() {
+        assert_eq!(
+          obj_dimensions(shape as u16, size as u16),
+          Some(dimensions),
+          "shape {} size {}",
+          shape,
+          size
+        );
+      }
+    }
+    for size in 0..4 {
+      assert_eq!(obj_dimensions(3, size), None);
+    }
+  }
+
+  #[test]
+  fn test_obj_attr_bits() {
+    let attr0 = ObjAttr0::new()
+      .with_y(0xA0)
+      .with_display_mode(ObjDisplayMode::AffineDoubleSize)
+      .with_effect_mode(ObjEffectMode::SemiTransparent)
+      .with_is_8bpp(true)
+      .with_shape(2);
+    assert_eq!(attr0.to_u16(), 0xA7A0);
+    assert!(ObjAttr0::from_u16(0x1000).mosaic());
+    assert_eq!(
+      ObjAttr0::from_u16(0x0800).effect_mode(),
+      ObjEffectMode::Window
+    );
+
+    let attr1 = ObjAttr1::new().with_x(0x1FF).with_hflip(true).with_size(3);
+    assert_eq!(attr1.to_u16(), 0xD1FF);
+    assert_eq!(ObjAttr1::from_u16(0x3E00).affine_index(), 31);
+    assert!(ObjAttr1::from_u16(0x2000).vflip());
+
+    let attr2 = ObjAttr2::new()
+      .with_tile(0x3FF)
+      .with_priority(2)
+      .with_palbank(5);
+    assert_eq!(attr2.to_u16(), 0x5BFF);
+
+    let attr = ObjAttr::from_u16s([0x4000, 0x8000, 0]);
+    assert_eq!(attr.dimensions(), Some((32, 16)));
+    assert_eq!(attr.to_u16s(), [0x4000, 0x8000, 0]);
+    assert_eq!(ObjAttr::from_u16s([0xC000, 0, 0]).dimensions(), None);
+  }
+}
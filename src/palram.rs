@@ -3,7 +3,7 @@
 //! This holds RGB palette entries. Each entry is 16-bit, 5-bits per channel,
 //! and the highest bit is ignored. The bits are laid out as follows:
 //!
-//! ```
+//! ```txt
 //! 0bXBBBBBGG_GGGRRRRR
 //! ```
 //!
@@ -29,13 +29,34 @@
 //! segment of memory. This isn't a big deal, since all palette entries are
 //! 16-bit values anyway.
 //!
+//! See [`Color`] for a type to build palette entry values with.
+//!
 //! * **Size:** 1kb
 //! * **Wait states:** 0
 //! * **Bus Size:** 16-bit
-//! * **Read/Write:** 16/32
+//! * **Reads:** 8/16/32
+//! * **Writes:** 16/32
 
 use super::*;
 
+/// Base Address of `PALRAM`.
+pub const PALRAM_START_ADDR: usize = 0x0500_0000;
+
+/// There is 1kb of `PALRAM`.
+///
+/// This is mirrored across the rest of the `0x05xx_xxxx` addresses.
+pub const PALRAM_COUNT: usize = 1024;
+
+/// Access info for `PALRAM`.
+pub const PALRAM_REGION_INFO: RegionInfo = RegionInfo {
+  bus_width: AccessWidth::Bits16,
+  reads: AccessWidths::ALL,
+  writes: AccessWidths::BITS16_32,
+  wait_states: 0,
+  dma_source: true,
+  dma_destination: true,
+};
+
 /// Base Address of the background palette.
 pub const BG_PALETTE_RAM_ADDR: usize = 0x0500_0000;
 
@@ -62,7 +83,7 @@ pub const fn index_bg_palette_4bpp(p: usize, i: usize) -> usize {
 /// Index the background palette to a 8bpp `index`.
 ///
 /// ## Panics
-/// `i` must be < 16.
+/// `i` must be < 256.
 pub const fn index_bg_palette_8bpp(i: usize) -> usize {
   let checked_index = const_bound_check(i, BG_PALETTE_RAM_COUNT);
   BG_PALETTE_RAM_ADDR + (BG_PALETTE_RAM_ENTRY_SIZE * checked_index)
@@ -91,7 +112,7 @@ pub const fn index_obj_palette_4bpp(p: usize, i: usize) -> usize {
 /// Index the object palette to a 8bpp `index`.
 ///
 /// ## Panics
-/// `i` must be < 16.
+/// `i` must be < 256.
 pub const fn index_obj_palette_8bpp(i: usize) -> usize {
   let checked_index = const_bound_check(i, OBJ_PALETTE_RAM_COUNT);
   OBJ_PALETTE_RAM_ADDR + (OBJ_PALETTE_RAM_ENTRY_SIZE * checked_index)
@@ -0,0 +1,331 @@
+//! Classifying addresses by memory region.
+//!
+//! Several regions are mirrored through the address space:
+//! * `EWRAM` is mirrored every 256kb through `0x02xx_xxxx`.
+//! * `IWRAM` is mirrored every 32kb through `0x03xx_xxxx`.
+//! * `PALRAM` and `OAM` are each mirrored every 1kb through their 16MB span.
+//! * `VRAM` is mirrored every 128kb, with the last 32kb of each mirror
+//!   repeating the object charblocks.
+//! * `ROM` appears three times, once for each wait state setting.
+//! * `SRAM` is mirrored every 64kb through `0x0Exx_xxxx` and `0x0Fxx_xxxx`.
+//!
+//! The "canonical" address of a location is the address within the base
+//! region (and, for `ROM`, the wait state 0 region).
+
+use super::*;
+
+/// The regions of the GBA's memory map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MemoryRegion {
+  /// The system ROM.
+  Bios,
+  /// External Work RAM.
+  Ewram,
+  /// Internal Work RAM.
+  Iwram,
+  /// IO Control Registers.
+  Io,
+  /// Palette RAM.
+  Palram,
+  /// Video RAM.
+  Vram,
+  /// Object Attribute Memory.
+  Oam,
+  /// ROM, accessed via the wait state 0 mirror.
+  RomWait0,
+  /// ROM, accessed via the wait state 1 mirror.
+  RomWait1,
+  /// ROM, accessed via the wait state 2 mirror.
+  RomWait2,
+  /// Save RAM.
+  Sram,
+}
+impl MemoryRegion {
+  /// The base address of the region.
+  pub const fn base_addr(self) -> usize {
+    match self {
+      MemoryRegion::Bios => BIOS_START_ADDR,
+      MemoryRegion::Ewram => EWRAM_START_ADDR,
+      MemoryRegion::Iwram => IWRAM_START_ADDR,
+      MemoryRegion::Io => IO_START_ADDR,
+      MemoryRegion::Palram => PALRAM_START_ADDR,
+      MemoryRegion::Vram => VRAM_BASE_ADDR,
+      MemoryRegion::Oam => OAM_START_ADDR,
+      MemoryRegion::RomWait0 => ROM_WAIT0_BASE_ADDR,
+      MemoryRegion::RomWait1 => ROM_WAIT1_BASE_ADDR,
+      MemoryRegion::RomWait2 => ROM_WAIT2_BASE_ADDR,
+      MemoryRegion::Sram => SRAM_BASE_ADDR,
+    }
+  }
+
+  /// The size of the region in bytes, not counting any mirrors.
+  ///
+  /// For `ROM` and `SRAM` this is the maximum size, the actual cart might have
+  /// less.
+  pub const fn size(self) -> usize {
+    match self {
+      MemoryRegion::Bios => BIOS_COUNT,
+      MemoryRegion::Ewram => EWRAM_COUNT,
+      MemoryRegion::Iwram => IWRAM_COUNT,
+      MemoryRegion::Io => IO_COUNT,
+      MemoryRegion::Palram => PALRAM_COUNT,
+      MemoryRegion::Vram => VRAM_COUNT,
+      MemoryRegion::Oam => OAM_COUNT,
+      MemoryRegion::RomWait0
+      | MemoryRegion::RomWait1
+      | MemoryRegion::RomWait2 => ROM_MAX_COUNT,
+      MemoryRegion::Sram => SRAM_COUNT,
+    }
+  }
+
+  /// If the region is one of the `ROM` mirrors.
+  pub const fn is_rom(self) -> bool {
+    matches!(
+      self,
+      MemoryRegion::RomWait0 | MemoryRegion::RomWait1 | MemoryRegion::RomWait2
+    )
+  }
+}
+
+/// Gets the region of an address, and the offset within that region after
+/// accounting for mirrors.
+///
+/// For the `ROM` mirrors the offset is relative to that mirror's base.
+pub(crate) const fn classify(addr: usize) -> Option<(MemoryRegion, usize)> {
+  let offset = addr & 0x00FF_FFFF;
+  match addr >> 24 {
+    0x00 if offset < BIOS_COUNT => Some((MemoryRegion::Bios, offset)),
+    0x02 => Some((MemoryRegion::Ewram, offset % EWRAM_COUNT)),
+    0x03 => Some((MemoryRegion::Iwram, offset % IWRAM_COUNT)),
+    0x04 if offset < IO_COUNT => Some((MemoryRegion::Io, offset)),
+    0x05 => Some((MemoryRegion::Palram, offset % PALRAM_COUNT)),
+    0x06 => {
+      let offset = offset % (128 * 1024);
+      if offset < VRAM_COUNT {
+        Some((MemoryRegion::Vram, offset))
+      } else {
+        Some((MemoryRegion::Vram, offset - (32 * 1024)))
+      }
+    }
+    0x07 => Some((MemoryRegion::Oam, offset % OAM_COUNT)),
+    0x08 | 0x09 => Some((MemoryRegion::RomWait0, addr % ROM_MAX_COUNT)),
+    0x0A | 0x0B => Some((MemoryRegion::RomWait1, addr % ROM_MAX_COUNT)),
+    0x0C | 0x0D => Some((MemoryRegion::RomWait2, addr % ROM_MAX_COUNT)),
+    0x0E | 0x0F => Some((MemoryRegion::Sram, offset % SRAM_COUNT)),
+    _ => None,
+  }
+}
+
+/// Gets the memory region that an address falls within.
+///
+/// Returns `None` if the address isn't mapped to any memory.
+pub const fn region_of(addr: usize) -> Option<MemoryRegion> {
+  match classify(addr) {
+    Some((region, _)) => Some(region),
+    None => None,
+  }
+}
+
+/// Gets the canonical address of an address, resolving any mirroring.
+///
+/// * Mirrors of a region are resolved to the base region.
+/// * All `ROM` mirrors are resolved to the wait state 0 region.
+///
+/// Returns `None` if the address isn't mapped to any memory.
+pub const fn canonical_addr(addr: usize) -> Option<usize> {
+  match classify(addr) {
+    Some((region, offset)) => {
+      if region.is_rom() {
+        Some(ROM_WAIT0_BASE_ADDR + offset)
+      } else {
+        Some(region.base_addr() + offset)
+      }
+    }
+    None => None,
+  }
+}
+
+/// The width of a single memory access.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum AccessWidth {
+  /// An 8-bit access.
+  Bits8,
+  /// A 16-bit access.
+  Bits16,
+  /// A 32-bit access.
+  Bits32,
+}
+impl AccessWidth {
+  /// The number of bytes in an access of this width.
+  pub const fn bytes(self) -> usize {
+    match self {
+      AccessWidth::Bits8 => 1,
+      AccessWidth::Bits16 => 2,
+      AccessWidth::Bits32 => 4,
+    }
+  }
+}
+
+/// A set of [`AccessWidth`] values.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct AccessWidths(u8);
+impl AccessWidths {
+  /// No access is allowed.
+  pub const NONE: Self = Self(0);
+  /// Only 8-bit access.
+  pub const BITS8: Self = Self(0b001);
+  /// 16-bit and 32-bit access.
+  pub const BITS16_32: Self = Self(0b110);
+  /// 8-bit, 16-bit, and 32-bit access.
+  pub const ALL: Self = Self(0b111);
+
+  const fn bit(width: AccessWidth) -> u8 {
+    1 << (width as u8)
+  }
+
+  /// If the set contains the given width.
+  pub const fn contains(self, width: AccessWidth) -> bool {
+    (self.0 & Self::bit(width)) != 0
+  }
+
+  /// The widths found in both sets.
+  pub const fn intersection(self, other: Self) -> Self {
+    Self(self.0 & other.0)
+  }
+
+  /// The widest width in the set, or `None` if the set is empty.
+  pub const fn widest(self) -> Option<AccessWidth> {
+    if self.contains(AccessWidth::Bits32) {
+      Some(AccessWidth::Bits32)
+    } else if self.contains(AccessWidth::Bits16) {
+      Some(AccessWidth::Bits16)
+    } else if self.contains(AccessWidth::Bits8) {
+      Some(AccessWidth::Bits8)
+    } else {
+      None
+    }
+  }
+}
+
+/// Info about how a memory region can be accessed.
+///
+/// Each module has a `_REGION_INFO` constant for its region, and you can also
+/// get the info with [`MemoryRegion::info`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RegionInfo {
+  /// The width of the data bus to the region.
+  ///
+  /// An access wider than the bus takes more than one bus cycle.
+  pub bus_width: AccessWidth,
+  /// The widths that can be validly read.
+  pub reads: AccessWidths,
+  /// The widths that can be validly written.
+  pub writes: AccessWidths,
+  /// The number of wait states (in the default configuration).
+  pub wait_states: u8,
+  /// If a DMA unit can read from the region.
+  pub dma_source: bool,
+  /// If a DMA unit can write to the region.
+  pub dma_destination: bool,
+}
+impl RegionInfo {
+  /// The widest access that can copy from the `src` region to this region,
+  /// or `None` if no copy is possible.
+  pub const fn widest_copy_from(self, src: Self) -> Option<AccessWidth> {
+    src.reads.intersection(self.writes).widest()
+  }
+
+  /// If a DMA unit can copy from the `src` region to this region.
+  pub const fn can_dma_from(self, src: Self) -> bool {
+    src.dma_source && self.dma_destination
+  }
+}
+
+impl MemoryRegion {
+  /// The access info for the region.
+  pub const fn info(self) -> RegionInfo {
+    match self {
+      MemoryRegion::Bios => BIOS_REGION_INFO,
+      MemoryRegion::Ewram => EWRAM_REGION_INFO,
+      MemoryRegion::Iwram => IWRAM_REGION_INFO,
+      MemoryRegion::Io => IO_REGION_INFO,
+      MemoryRegion::Palram => PALRAM_REGION_INFO,
+      MemoryRegion::Vram => VRAM_REGION_INFO,
+      MemoryRegion::Oam => OAM_REGION_INFO,
+      MemoryRegion::RomWait0
+      | MemoryRegion::RomWait1
+      | MemoryRegion::RomWait2 => ROM_REGION_INFO,
+      MemoryRegion::Sram => SRAM_REGION_INFO,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_region_of() {
+    assert_eq!(region_of(0x0000_0000), Some(MemoryRegion::Bios));
+    assert_eq!(region_of(0x0200_0000), Some(MemoryRegion::Ewram));
+    assert_eq!(region_of(0x0300_7FFF), Some(MemoryRegion::Iwram));
+    assert_eq!(region_of(0x0400_0000), Some(MemoryRegion::Io));
+    assert_eq!(region_of(0x0500_0000), Some(MemoryRegion::Palram));
+    assert_eq!(region_of(0x0601_7FFF), Some(MemoryRegion::Vram));
+    assert_eq!(region_of(0x0700_0000), Some(MemoryRegion::Oam));
+    assert_eq!(region_of(0x0800_0000), Some(MemoryRegion::RomWait0));
+    assert_eq!(region_of(0x09FF_FFFF), Some(MemoryRegion::RomWait0));
+    assert_eq!(region_of(0x0A00_0000), Some(MemoryRegion::RomWait1));
+    assert_eq!(region_of(0x0C00_0000), Some(MemoryRegion::RomWait2));
+    assert_eq!(region_of(0x0E00_0000), Some(MemoryRegion::Sram));
+  }
+
+  #[test]
+  fn test_region_of_unmapped() {
+    assert_eq!(region_of(BIOS_START_ADDR + BIOS_COUNT), None);
+    assert_eq!(region_of(0x0100_0000), None);
+    assert_eq!(region_of(IO_START_ADDR + IO_COUNT), None);
+    assert_eq!(region_of(0x1000_0000), None);
+    assert_eq!(canonical_addr(0x0100_0000), None);
+  }
+
+  #[test]
+  fn test_canonical_addr() {
+    // one mirror up in each region
+    assert_eq!(canonical_addr(0x0204_0010), Some(0x0200_0010));
+    assert_eq!(canonical_addr(0x0300_8010), Some(0x0300_0010));
+    assert_eq!(canonical_addr(0x0500_0410), Some(0x0500_0010));
+    assert_eq!(canonical_addr(0x0602_0010), Some(0x0600_0010));
+    assert_eq!(canonical_addr(0x0700_0410), Some(0x0700_0010));
+    assert_eq!(canonical_addr(0x0E01_0010), Some(0x0E00_0010));
+    assert_eq!(canonical_addr(0x0F00_0010), Some(0x0E00_0010));
+    // mirrors near the top of each region's span
+    assert_eq!(canonical_addr(0x02FF_FFFF), Some(0x0203_FFFF));
+    assert_eq!(canonical_addr(0x03FF_FFFF), Some(0x0300_7FFF));
+    // addresses that aren't mirrored stay the same
+    assert_eq!(canonical_addr(0x0400_0004), Some(0x0400_0004));
+    assert_eq!(canonical_addr(0x0000_3FFF), Some(0x0000_3FFF));
+  }
+
+  #[test]
+  fn test_canonical_addr_vram() {
+    // The last 32kb of each 128kb mirror repeats the object charblocks.
+    assert_eq!(canonical_addr(0x0601_8000), Some(0x0601_0000));
+    assert_eq!(canonical_addr(0x0601_FFFE), Some(0x0601_7FFE));
+    assert_eq!(canonical_addr(0x0603_8000), Some(0x0601_0000));
+    assert_eq!(canonical_addr(0x0601_7FFE), Some(0x0601_7FFE));
+  }
+
+  #[test]
+  fn test_canonical_addr_rom() {
+    assert_eq!(canonical_addr(0x0A00_0000), Some(0x0800_0000));
+    assert_eq!(canonical_addr(0x0C00_0000), Some(0x0800_0000));
+    assert_eq!(canonical_addr(0x0D00_1234), Some(0x0900_1234));
+    assert_eq!(classify(0x0A00_0100), Some((MemoryRegion::RomWait1, 0x100)));
+    assert_eq!(
+      classify(0x0D00_0000),
+      Some((MemoryRegion::RomWait2, 0x0100_0000))
+    );
+  }
+}
@@ -9,13 +9,17 @@
 //! * **Wait State 2:** `0x0C00_0000`.
 //!
 //! By using the [`WAITCNT_ADDR`] you can modify the wait states required for
-//! each of the three ROM mirrors independently.
+//! each of the three ROM mirrors independently. See [`WaitControl`] for the
+//! fields of that register, and [`rom_wait_state_of`] to find which fields
+//! apply to a given address.
 //!
 //! * **Size:** up to 32MB
 //! * **Wait states:** variable (default is 4), but always more than zero.
 //! * **Bus Size:** 16-bit
 //! * **Reads:** 8/16/32
 
+use super::*;
+
 /// ROM base address for wait state 0.
 pub const ROM_WAIT0_BASE_ADDR: usize = 0x0800_0000;
 
@@ -24,3 +28,16 @@ pub const ROM_WAIT1_BASE_ADDR: usize = 0x0A00_0000;
 
 /// ROM base address for wait state 2.
 pub const ROM_WAIT2_BASE_ADDR: usize = 0x0C00_0000;
+
+/// The largest possible ROM is 32MB, which is also the size of each mirror.
+pub const ROM_MAX_COUNT: usize = 32 * 1024 * 1024;
+
+/// Access info for `ROM` (all three mirrors).
+pub const ROM_REGION_INFO: RegionInfo = RegionInfo {
+  bus_width: AccessWidth::Bits16,
+  reads: AccessWidths::ALL,
+  writes: AccessWidths::NONE,
+  wait_states: 4,
+  dma_source: true,
+  dma_destination: false,
+};
@@ -0,0 +1,56 @@
+//! Text mode screen entry values.
+//!
+//! Each entry of a text mode screenblock is a `u16` that picks the tile to
+//! draw at that spot, how to flip it, and which palbank it uses. See
+//! [`index_text_screen_entry`] for the address of a given entry.
+
+use super::*;
+
+/// A text mode screen entry.
+///
+/// * 0-9: Tile index
+/// * 10: Horizontal flip
+/// * 11: Vertical flip
+/// * 12-15: Palbank (4bpp only)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct TextScreenEntry(u16);
+impl TextScreenEntry {
+  u16_newtype_basics!();
+
+  /// Info about each field.
+  pub const FIELDS: &'static [FieldInfo] = &[
+    FieldInfo::new("tile", 0, 10),
+    FieldInfo::new("hflip", 10, 1),
+    FieldInfo::new("vflip", 11, 1),
+    FieldInfo::new("palbank", 12, 4),
+  ];
+
+  u16_int_field!(
+    /// The tile index, 0 through 1023.
+    ///
+    /// This is relative to the background's charblock, and counts in 4bpp or
+    /// 8bpp tiles to match the background.
+    0 - 9,
+    tile,
+    with_tile
+  );
+  u16_bool_field!(
+    /// If the tile is flipped horizontally.
+    10,
+    hflip,
+    with_hflip
+  );
+  u16_bool_field!(
+    /// If the tile is flipped vertically.
+    11,
+    vflip,
+    with_vflip
+  );
+  u16_int_field!(
+    /// The palbank used by 4bpp tiles, 0 through 15.
+    12 - 15,
+    palbank,
+    with_palbank
+  );
+}
@@ -0,0 +1,196 @@
+//! A shadow copy of `OAM`.
+//!
+//! Changing `OAM` while the display is drawing can make objects tear, so the
+//! usual approach is to keep a copy of `OAM` in normal memory, edit that copy
+//! during the frame, and then copy all of it into `OAM` during VBlank.
+//!
+//! A [`ShadowOam`] has exactly the same layout as `OAM`, with the affine
+//! parameters spread between the object attributes, so the whole thing can be
+//! copied with plain 32-bit writes.
+
+use super::*;
+
+/// The `pa`, `pb`, `pc`, and `pd` values of an affine parameter entry.
+///
+/// Each value is 8.8 fixed point.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ObjAffineParams {
+  /// The `pa` value.
+  pub pa: I8F8,
+  /// The `pb` value.
+  pub pb: I8F8,
+  /// The `pc` value.
+  pub pc: I8F8,
+  /// The `pd` value.
+  pub pd: I8F8,
+}
+impl ObjAffineParams {
+  /// The parameters that leave an object unchanged.
+  pub const IDENTITY: Self = Self {
+    pa: I8F8::ONE,
+    pb: I8F8::ZERO,
+    pc: I8F8::ZERO,
+    pd: I8F8::ONE,
+  };
+}
+
+/// The `u16` index of an `OAM` address.
+const fn u16_index_of(addr: usize) -> usize {
+  (addr - OAM_START_ADDR) / 2
+}
+
+/// The `u16` distance from one affine parameter value to the next.
+const AFFINE_STEP: usize =
+  u16_index_of(OBJ_AFFINE_PB_BASE_ADDR) - u16_index_of(OBJ_AFFINE_PA_BASE_ADDR);
+
+/// A copy of `OAM` in normal memory.
+///
+/// The object attribute and affine parameter entries are indexed the same as
+/// with [`index_obj_attr`] and [`index_obj_affine_param`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[repr(C, align(4))]
+pub struct ShadowOam([u16; OAM_COUNT / 2]);
+const_assert!(core::mem::size_of::<ShadowOam>() == OAM_COUNT);
+impl Default for ShadowOam {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+impl ShadowOam {
+  /// Makes a new copy with every object hidden, and every affine parameter
+  /// entry set to the identity.
+  pub const fn new() -> Self {
+    let mut oam = Self([0; OAM_COUNT / 2]);
+    let mut i = 0;
+    while i < OBJ_ATTR_COUNT {
+      let base = u16_index_of(index_obj_attr(i));
+      oam.0[base] = ObjAttr::HIDDEN.attr0.to_u16();
+      i += 1;
+    }
+    let mut i = 0;
+    while i < OBJ_AFFINE_COUNT {
+      let base = u16_index_of(index_obj_affine_param(i));
+      oam.0[base] = ObjAffineParams::IDENTITY.pa.to_register_bits();
+      oam.0[base + 3 * AFFINE_STEP] =
+        ObjAffineParams::IDENTITY.pd.to_register_bits();
+      i += 1;
+    }
+    oam
+  }
+
+  /// Gets the attributes of a given object.
+  ///
+  /// ## Panics
+  /// `i` must be < 128.
+  pub const fn obj(&self, i: usize) -> ObjAttr {
+    let base = u16_index_of(index_obj_attr(i));
+    ObjAttr::from_u16s([self.0[base], self.0[base + 1], self.0[base + 2]])
+  }
+
+  /// Sets the attributes of a given object.
+  ///
+  /// ## Panics
+  /// `i` must be < 128.
+  pub fn set_obj(&mut self, i: usize, attr: ObjAttr) {
+    let base = u16_index_of(index_obj_attr(i));
+    self.0[base..base + 3].copy_from_slice(&attr.to_u16s());
+  }
+
+  /// Gets a given affine parameter entry.
+  ///
+  /// ## Panics
+  /// `i` must be < 32.
+  pub const fn affine(&self, i: usize) -> ObjAffineParams {
+    let base = u16_index_of(index_obj_affine_param(i));
+    ObjAffineParams {
+      pa: I8F8::from_register_bits(self.0[base]),
+      pb: I8F8::from_register_bits(self.0[base + AFFINE_STEP]),
+      pc: I8F8::from_register_bits(self.0[base + 2 * AFFINE_STEP]),
+      pd: I8F8::from_register_bits(self.0[base + 3 * AFFINE_STEP]),
+    }
+  }
+
+  /// Sets a given affine parameter entry.
+  ///
+  /// ## Panics
+  /// `i` must be < 32.
+  pub fn set_affine(&mut self, i: usize, params: ObjAffineParams) {
+    let base = u16_index_of(index_obj_affine_param(i));
+    self.0[base] = params.pa.to_register_bits();
+    self.0[base + AFFINE_STEP] = params.pb.to_register_bits();
+    self.0[base + 2 * AFFINE_STEP] = params.pc.to_register_bits();
+    self.0[base + 3 * AFFINE_STEP] = params.pd.to_register_bits();
+  }
+
+  /// The raw `u16` values, in `OAM` order.
+  pub const fn as_u16s(&self) -> &[u16; OAM_COUNT / 2] {
+    &self.0
+  }
+
+  /// The `(address, value)` of each 32-bit write that copies the buffer into
+  /// `OAM`, in address order.
+  ///
+  /// This is what [`commit`](Self::commit) writes, and it can be used to
+  /// apply the buffer somewhere else, such as to a `MockBus`.
+  pub fn writes(&self) -> impl Iterator<Item = (usize, u32)> + '_ {
+    let u = &self.0;
+    (0..OAM_COUNT / 4).map(move |i| {
+      let word = u[i * 2] as u32 | ((u[i * 2 + 1] as u32) << 16);
+      (OAM_START_ADDR + i * 4, word)
+    })
+  }
+
+  /// Copies the whole buffer into `OAM`, using 32-bit volatile writes.
+  ///
+  /// ## Safety
+  /// * This must run on the GBA (or an emulator), where `OAM` is mapped.
+  /// * To avoid tearing, call this during VBlank or forced blank.
+  pub unsafe fn commit(&self) {
+    for (addr, word) in self.writes() {
+      (addr as *mut u32).write_volatile(word);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_new() {
+    let oam = ShadowOam::new();
+    for i in 0..OBJ_ATTR_COUNT {
+      assert_eq!(oam.obj(i), ObjAttr::HIDDEN);
+    }
+    for i in 0..OBJ_AFFINE_COUNT {
+      assert_eq!(oam.affine(i), ObjAffineParams::IDENTITY);
+    }
+  }
+
+  #[test]
+  fn test_writes() {
+    let mut oam = ShadowOam::new();
+    let attr = ObjAttr::from_u16s([0x1234, 0x5678, 0x9ABC]);
+    oam.set_obj(1, attr);
+    assert_eq!(oam.writes().count(), OAM_COUNT / 4);
+    let mut writes = oam.writes().skip(2);
+    assert_eq!(writes.next(), Some((OAM_START_ADDR + 8, 0x5678_1234)));
+    let (addr, word) = writes.next().unwrap();
+    assert_eq!(addr, OAM_START_ADDR + 12);
+    assert_eq!(word & 0xFFFF, 0x9ABC);
+  }
+
+  #[cfg(feature = "std")]
+  #[test]
+  fn test_writes_to_bus() {
+    let mut oam = ShadowOam::new();
+    oam.set_obj(5, ObjAttr::from_u16s([1, 2, 3]));
+    let mut bus = MockBus::new();
+    for (addr, word) in oam.writes() {
+      bus.write_u32(addr, word);
+    }
+    for (i, u) in oam.as_u16s().iter().enumerate() {
+      assert_eq!(bus.read_u16(OAM_START_ADDR + i * 2), *u);
+    }
+  }
+}
@@ -19,7 +19,9 @@
 //! * **Size:** up to 64kb
 //! * **Wait states:** variable (default is 4), but always more than zero.
 //! * **Bus Size:** 8-bit
-//! * **Reads:** 8
+//! * **Read/Write:** 8
+
+use super::*;
 
 /// Base Address of `SRAM`
 pub const SRAM_BASE_ADDR: usize = 0x0E00_0000;
@@ -32,3 +34,13 @@ pub const SRAM_ENTRY_SIZE: usize = 1;
 /// Some carts have less than 64kb, in which case the available memory is
 /// mirrored out to 64kb.
 pub const SRAM_COUNT: usize = 64 * 1024;
+
+/// Access info for `SRAM`.
+pub const SRAM_REGION_INFO: RegionInfo = RegionInfo {
+  bus_width: AccessWidth::Bits8,
+  reads: AccessWidths::BITS8,
+  writes: AccessWidths::BITS8,
+  wait_states: 4,
+  dma_source: false,
+  dma_destination: false,
+};
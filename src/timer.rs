@@ -0,0 +1,320 @@
+//! Timer control values and reload calculations.
+//!
+//! Each timer counts up from its reload value, and when it overflows past
+//! `0xFFFF` it's set back to the reload value. Writing [`TM0CNT_L_ADDR`] (and
+//! the others) sets the reload value, and reading it gives the current count.
+//!
+//! A timer counts once every 1, 64, 256, or 1024 CPU cycles, depending on the
+//! prescaler. A cascading timer instead counts once each time the timer before
+//! it overflows, so two timers can be paired to count longer periods.
+//!
+//! The reload helpers round the number of counts to the nearest integer, and
+//! give `None` if the target can't be reached with the given prescaler. The
+//! cascade helpers also give `None` if the count can't be split exactly
+//! between the two timers.
+
+use super::*;
+
+/// The CPU clock, in cycles per second (16.78MHz).
+pub const CPU_FREQUENCY: u32 = 16_777_216;
+
+/// How often a timer counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u16)]
+pub enum TimerPrescaler {
+  /// Once per CPU cycle.
+  Div1 = 0,
+  /// Once per 64 CPU cycles.
+  Div64 = 1,
+  /// Once per 256 CPU cycles.
+  Div256 = 2,
+  /// Once per 1024 CPU cycles.
+  Div1024 = 3,
+}
+impl TimerPrescaler {
+  /// The number of CPU cycles per count.
+  pub const fn divider(self) -> u32 {
+    match self {
+      TimerPrescaler::Div1 => 1,
+      TimerPrescaler::Div64 => 64,
+      TimerPrescaler::Div256 => 256,
+      TimerPrescaler::Div1024 => 1024,
+    }
+  }
+}
+
+/// A value for [`TM0CNT_H_ADDR`] through [`TM3CNT_H_ADDR`].
+///
+/// * 0-1: Prescaler (1, 64, 256, or 1024 cycles)
+/// * 2: Cascade (count when the previous timer overflows, not timer 0)
+/// * 6: Interrupt on overflow
+/// * 7: Enable
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct TimerControl(u16);
+impl TimerControl {
+  u16_newtype_basics!();
+
+  /// Info about each field.
+  pub const FIELDS: &'static [FieldInfo] = &[
+    FieldInfo::new("prescaler", 0, 2),
+    FieldInfo::new("cascade", 2, 1),
+    FieldInfo::new("irq", 6, 1),
+    FieldInfo::new("enable", 7, 1),
+  ];
+
+  /// How often the timer counts.
+  ///
+  /// Ignored when [`cascade`](Self::cascade) is set.
+  pub const fn prescaler(self) -> TimerPrescaler {
+    match self.0 & 0b11 {
+      0 => TimerPrescaler::Div1,
+      1 => TimerPrescaler::Div64,
+      2 => TimerPrescaler::Div256,
+      _ => TimerPrescaler::Div1024,
+    }
+  }
+
+  /// Replaces the [`prescaler`](Self::prescaler) value.
+  pub const fn with_prescaler(self, prescaler: TimerPrescaler) -> Self {
+    Self((self.0 & !0b11) | (prescaler as u16))
+  }
+
+  u16_bool_field!(
+    /// If the timer counts when the previous timer overflows.
+    ///
+    /// Timer 0 can't cascade.
+    2,
+    cascade,
+    with_cascade
+  );
+  u16_bool_field!(
+    /// If an interrupt is sent when the timer overflows.
+    6,
+    irq,
+    with_irq
+  );
+  u16_bool_field!(
+    /// If the timer is enabled.
+    7,
+    enable,
+    with_enable
+  );
+}
+
+/// The largest number of counts between overflows of one timer.
+const MAX_TICKS: u64 = 0x1_0000;
+
+/// The reload value for a timer that overflows every `ticks` counts.
+const fn reload_of(ticks: u64) -> Option<u16> {
+  if ticks == 0 || ticks > MAX_TICKS {
+    None
+  } else {
+    Some((MAX_TICKS - ticks) as u16)
+  }
+}
+
+/// The reload values for a cascaded pair that overflows every `ticks` counts
+/// of the low timer's prescaler.
+///
+/// Returns `None` if `ticks` can't be split exactly into the periods of two
+/// timers.
+const fn cascade_reload_of(ticks: u64) -> Option<(u16, u16)> {
+  if ticks == 0 || ticks > MAX_TICKS * MAX_TICKS {
+    return None;
+  }
+  // Look for an exact split of the ticks, starting with the fastest low timer.
+  let mut low = ticks / MAX_TICKS;
+  if low * MAX_TICKS < ticks {
+    low += 1;
+  }
+  while low <= MAX_TICKS {
+    let high = ticks / low;
+    if high * low == ticks {
+      return Some(((MAX_TICKS - low) as u16, (MAX_TICKS - high) as u16));
+    }
+    low += 1;
+  }
+  None
+}
+
+/// Divides, rounding to the nearest integer.
+const fn div_round(n: u64, d: u64) -> u64 {
+  (n + d / 2) / d
+}
+
+/// The reload value for a timer to overflow `hz` times per second.
+///
+/// The number of counts between overflows is rounded to the nearest integer,
+/// so the actual frequency can be slightly off from `hz`. Use
+/// [`timer_overflow_cycles`] to get the exact result.
+///
+/// Returns `None` if `hz` is 0, or is too slow for the prescaler.
+pub const fn timer_reload_for_frequency(
+  hz: u32,
+  prescaler: TimerPrescaler,
+) -> Option<u16> {
+  if hz == 0 {
+    return None;
+  }
+  reload_of(div_round(
+    CPU_FREQUENCY as u64,
+    hz as u64 * prescaler.divider() as u64,
+  ))
+}
+
+/// The reload value for a timer to overflow once every `cycles` CPU cycles.
+///
+/// `cycles` is rounded to the nearest multiple of the prescaler's divider.
+///
+/// Returns `None` if the period rounds to 0, or is too long for the
+/// prescaler.
+pub const fn timer_reload_for_period(
+  cycles: u64,
+  prescaler: TimerPrescaler,
+) -> Option<u16> {
+  reload_of(div_round(cycles, prescaler.divider() as u64))
+}
+
+/// The reload values for a cascaded pair of timers to overflow `hz` times per
+/// second.
+///
+/// The output is `(low, high)`, where the low timer uses the prescaler and the
+/// high timer cascades from it. The high timer's overflow is the one at the
+/// target frequency.
+///
+/// As with [`timer_reload_for_frequency`], the number of counts between
+/// overflows is rounded to the nearest integer.
+///
+/// Returns `None` if `hz` is 0, or if the rounded count can't be split
+/// exactly between the two timers.
+pub const fn timer_cascade_reload_for_frequency(
+  hz: u32,
+  prescaler: TimerPrescaler,
+) -> Option<(u16, u16)> {
+  if hz == 0 {
+    return None;
+  }
+  cascade_reload_of(div_round(
+    CPU_FREQUENCY as u64,
+    hz as u64 * prescaler.divider() as u64,
+  ))
+}
+
+/// The reload values for a cascaded pair of timers to overflow once every
+/// `cycles` CPU cycles.
+///
+/// The output is `(low, high)`, as with
+/// [`timer_cascade_reload_for_frequency`]. `cycles` is rounded to the nearest
+/// multiple of the prescaler's divider.
+///
+/// Returns `None` if the rounded period can't be split exactly between the
+/// two timers (for example, if it's a prime number larger than 65536).
+pub const fn timer_cascade_reload_for_period(
+  cycles: u64,
+  prescaler: TimerPrescaler,
+) -> Option<(u16, u16)> {
+  cascade_reload_of(div_round(cycles, prescaler.divider() as u64))
+}
+
+/// The number of CPU cycles between overflows of a timer with the given reload
+/// value.
+pub const fn timer_overflow_cycles(
+  reload: u16,
+  prescaler: TimerPrescaler,
+) -> u32 {
+  (MAX_TICKS as u32 - reload as u32) * prescaler.divider()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_reload_for_frequency() {
+    assert_eq!(
+      timer_reload_for_frequency(32768, TimerPrescaler::Div1),
+      Some(0xFE00)
+    );
+    assert_eq!(timer_reload_for_frequency(0, TimerPrescaler::Div1), None);
+    assert_eq!(timer_reload_for_frequency(1, TimerPrescaler::Div1), None);
+    assert_eq!(
+      timer_reload_for_frequency(1, TimerPrescaler::Div256),
+      Some(0)
+    );
+  }
+
+  #[test]
+  fn test_reload_for_period() {
+    assert_eq!(
+      timer_reload_for_period(1024, TimerPrescaler::Div64),
+      Some(0xFFF0)
+    );
+    assert_eq!(
+      timer_reload_for_period(0x1_0000, TimerPrescaler::Div1),
+      Some(0)
+    );
+    assert_eq!(
+      timer_reload_for_period(0x1_0001, TimerPrescaler::Div1),
+      None
+    );
+    assert_eq!(timer_reload_for_period(0, TimerPrescaler::Div1), None);
+  }
+
+  #[test]
+  fn test_cascade_reload() {
+    assert_eq!(
+      timer_cascade_reload_for_frequency(1, TimerPrescaler::Div1024),
+      Some((0xFFFF, 0xC000))
+    );
+    assert_eq!(
+      timer_cascade_reload_for_period(
+        60 * CPU_FREQUENCY as u64,
+        TimerPrescaler::Div1024
+      ),
+      Some((0xFFF1, 0))
+    );
+    assert_eq!(
+      timer_cascade_reload_for_period(
+        0x1_0000 * 0x1_0000,
+        TimerPrescaler::Div1
+      ),
+      Some((0, 0))
+    );
+    assert_eq!(
+      timer_cascade_reload_for_period(4_294_967_291, TimerPrescaler::Div1),
+      None
+    );
+    assert_eq!(
+      timer_cascade_reload_for_frequency(0, TimerPrescaler::Div1),
+      None
+    );
+  }
+
+  #[test]
+  fn test_cascade_reload_is_exact() {
+    for &(cycles, splits) in &[
+      (1_u64, true),
+      (70_000, true),
+      (1_000_000, true),
+      (12_345_678, true),
+      (65_537, false),
+      (4_294_967_290, false),
+    ] {
+      let reload =
+        timer_cascade_reload_for_period(cycles, TimerPrescaler::Div1);
+      assert_eq!(reload.is_some(), splits, "{}", cycles);
+      if let Some((low, high)) = reload {
+        let low = 0x1_0000 - low as u64;
+        let high = 0x1_0000 - high as u64;
+        assert_eq!(low * high, cycles);
+      }
+    }
+  }
+
+  #[test]
+  fn test_overflow_cycles() {
+    assert_eq!(timer_overflow_cycles(0, TimerPrescaler::Div1), 0x1_0000);
+    assert_eq!(timer_overflow_cycles(0xFFFF, TimerPrescaler::Div1024), 1024);
+  }
+}
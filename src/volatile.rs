@@ -0,0 +1,95 @@
+//! Typed volatile addresses.
+//!
+//! This module is only available with the `volatile` feature.
+//!
+//! A [`VolAddress`] is an address along with the type of data found there and
+//! if reading and/or writing is allowed. Each IO register has a typed version
+//! in the [`vol`](crate::io::vol) module, with the same name as the raw
+//! address.
+//!
+//! * If the `R` parameter is [`Safe`], then the address can be read.
+//! * If the `W` parameter is [`Safe`], then the address can be written.
+//! * Otherwise the parameter is `()`, and that operation isn't available.
+//!
+//! So, for example, writing to `vol::VCOUNT_ADDR` (read-only) or reading from
+//! `vol::BG0HOFS_ADDR` (write-only) is a compile error.
+
+use core::{fmt, marker::PhantomData};
+
+/// Marks that an operation is safe to perform on a [`VolAddress`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Safe;
+
+/// A volatile address of some `T` value.
+///
+/// * `R` is [`Safe`] if the address can be read, otherwise `()`.
+/// * `W` is [`Safe`] if the address can be written, otherwise `()`.
+#[repr(transparent)]
+pub struct VolAddress<T, R, W> {
+  address: usize,
+  target: PhantomData<T>,
+  read_status: PhantomData<R>,
+  write_status: PhantomData<W>,
+}
+impl<T, R, W> VolAddress<T, R, W> {
+  /// Wraps an address value.
+  ///
+  /// ## Safety
+  /// * The address must be non-null and aligned for `T`.
+  /// * Reads (if `R` is [`Safe`]) and writes (if `W` is [`Safe`]) of `T` at
+  ///   the address must be valid for the entire life of the program.
+  pub const unsafe fn new(address: usize) -> Self {
+    Self {
+      address,
+      target: PhantomData,
+      read_status: PhantomData,
+      write_status: PhantomData,
+    }
+  }
+
+  /// Unwrap the value into a `usize`.
+  pub const fn as_usize(self) -> usize {
+    self.address
+  }
+
+  /// Gets the address as a const pointer.
+  pub const fn as_ptr(self) -> *const T {
+    self.address as *const T
+  }
+
+  /// Gets the address as a mut pointer.
+  pub const fn as_mut_ptr(self) -> *mut T {
+    self.address as *mut T
+  }
+}
+impl<T: Copy, W> VolAddress<T, Safe, W> {
+  /// Volatile reads the current value.
+  pub fn read(self) -> T {
+    // Safety: the requirements of `new` make this valid.
+    unsafe { self.as_ptr().read_volatile() }
+  }
+}
+impl<T: Copy, R> VolAddress<T, R, Safe> {
+  /// Volatile writes a new value.
+  pub fn write(self, t: T) {
+    // Safety: the requirements of `new` make this valid.
+    unsafe { self.as_mut_ptr().write_volatile(t) }
+  }
+}
+impl<T, R, W> Clone for VolAddress<T, R, W> {
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+impl<T, R, W> Copy for VolAddress<T, R, W> {}
+impl<T, R, W> PartialEq for VolAddress<T, R, W> {
+  fn eq(&self, other: &Self) -> bool {
+    self.address == other.address
+  }
+}
+impl<T, R, W> Eq for VolAddress<T, R, W> {}
+impl<T, R, W> fmt::Debug for VolAddress<T, R, W> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "VolAddress({:#010X})", self.address)
+  }
+}
@@ -32,8 +32,10 @@
 //!     * 64x32: one screenblock, and the next screenblock on the right
 //!       (horizontal).
 //!     * 32x64: one screenblock, and the next screenblock beneath (vertical).
-//!     * 64c64: the base screenblock, then down, then right, then down right
+//!     * 64x64: the base screenblock, then right, then down, then down right
 //!       (large square).
+//!   * See [`index_text_screen_entry`] to find a given entry of a layer, and
+//!     [`TextScreenEntry`] for the entry values.
 //! * **Affine Mode:**
 //!   * 1 byte per entry, entry count per screenblock depends on the background
 //!     size: 16x16 (256b), 32x32 (1kb), 64x64 (4kb), or 128x128 (16kb).
@@ -44,18 +46,36 @@
 //! segment of memory. This can cause a problem when working with the video mode
 //! 4 bitmap, or with the video mode 1 or 2 affine screenblocks. To modify a
 //! single byte correctly you must read at least 16-bits, mask in the new 8-bit
-//! value, and then write back the larger value.
+//! value, and then write back the larger value. Single byte writes to the
+//! object tile region of `VRAM` are ignored entirely.
 //!
 //! * **Size:** 96kb
 //! * **Wait states:** 0
 //! * **Bus Size:** 16-bit
-//! * **Read/Write:** 16/32
+//! * **Reads:** 8/16/32
+//! * **Writes:** 16/32
 
 use super::*;
 
 /// The base address for `VRAM`, regardless of video mode.
 pub const VRAM_BASE_ADDR: usize = 0x0600_0000;
 
+/// There is 96kb of `VRAM`.
+///
+/// This is mirrored every 128kb across the rest of the `0x06xx_xxxx` addresses,
+/// with the final 32kb of each mirror repeating the object charblocks.
+pub const VRAM_COUNT: usize = 96 * 1024;
+
+/// Access info for `VRAM`.
+pub const VRAM_REGION_INFO: RegionInfo = RegionInfo {
+  bus_width: AccessWidth::Bits16,
+  reads: AccessWidths::ALL,
+  writes: AccessWidths::BITS16_32,
+  wait_states: 0,
+  dma_source: true,
+  dma_destination: true,
+};
+
 /// The size of a 4bpp tile.
 pub const TILE_4BPP_SIZE: usize = 32;
 
@@ -104,7 +124,7 @@ impl CharblockAddress {
   /// `i` must be < 512.
   pub const fn index_tile_4bpp(self, i: usize) -> usize {
     let checked_index = const_bound_check(i, CHARBLOCK_4BPP_COUNT);
-    self.0 + (CHARBLOCK_SIZE * checked_index)
+    self.0 + (TILE_4BPP_SIZE * checked_index)
   }
 
   /// Indexes to a given 8bpp tile within this charblock.
@@ -113,7 +133,7 @@ impl CharblockAddress {
   /// `i` must be < 256.
   pub const fn index_tile_8bpp(self, i: usize) -> usize {
     let checked_index = const_bound_check(i, CHARBLOCK_8BPP_COUNT);
-    self.0 + (CHARBLOCK_SIZE * checked_index)
+    self.0 + (TILE_8BPP_SIZE * checked_index)
   }
 
   /// Unwrap the value into a `usize`.
@@ -135,7 +155,7 @@ pub const CHARBLOCK_OBJ_COUNT: usize = 2;
 ///
 /// Object tile indexes always index into memory as if they were 4bpp.
 pub const CHARBLOCK_OBJ_TILE_COUNT: usize =
-  CHARBLOCK_OBJ_COUNT * TILE_4BPP_SIZE;
+  CHARBLOCK_OBJ_COUNT * CHARBLOCK_4BPP_COUNT;
 
 /// Index to a given object tile.
 ///
@@ -152,15 +172,6 @@ pub const fn index_obj_tile(i: usize) -> usize {
   CHARBLOCK_OBJ_BASE_ADDR + (TILE_4BPP_SIZE * checked_index)
 }
 
-/// Index to the start of a particular screenblock.
-///
-/// ## Panics
-/// `i` must be < 32.
-pub const fn index_screenblock(i: usize) -> usize {
-  let checked_index = const_bound_check(i, 32);
-  CHARBLOCK_OBJ_BASE_ADDR + (TILE_4BPP_SIZE * checked_index)
-}
-
 /// The size of a text mode screen entry.
 pub const TEXT_SCREENBLOCK_ENTRY_SIZE: usize = 2;
 
@@ -172,6 +183,54 @@ pub const TEXT_SCREENBLOCK_SIZE: usize =
   TEXT_SCREENBLOCK_ENTRY_SIZE * TEXT_SCREENBLOCK_ENTRY_COUNT;
 const_assert!(TEXT_SCREENBLOCK_SIZE == 2 * 1024);
 
+/// There are 32 screenblock indexes.
+pub const SCREENBLOCK_COUNT: usize = 32;
+
+/// Index to the start of a particular screenblock.
+///
+/// Screenblock indexes are always in terms of 2kb, regardless of the actual
+/// size of the screenblock.
+///
+/// ## Panics
+/// `i` must be < 32.
+pub const fn index_screenblock(i: usize) -> usize {
+  let checked_index = const_bound_check(i, SCREENBLOCK_COUNT);
+  VRAM_BASE_ADDR + (TEXT_SCREENBLOCK_SIZE * checked_index)
+}
+
+/// Index to a particular entry of a text mode background.
+///
+/// * `screenblock`: The base screenblock of the background.
+/// * `bg_size`: The size of the background, as with
+///   [`BackgroundControl::size`].
+/// * `x` and `y`: The position of the entry, in tiles.
+///
+/// Backgrounds larger than 32x32 use more than one screenblock. Each
+/// screenblock holds a 32x32 area, and they're in the order of: the base
+/// screenblock, then the one to the right, then the one below, then the one
+/// below and to the right.
+///
+/// ## Panics
+/// * `bg_size` must be < 4.
+/// * `x` must be < the width of the background (32 or 64).
+/// * `y` must be < the height of the background (32 or 64).
+/// * The screenblock holding the entry must be < 32.
+pub const fn index_text_screen_entry(
+  screenblock: usize,
+  bg_size: usize,
+  x: usize,
+  y: usize,
+) -> usize {
+  let checked_size = const_bound_check(bg_size, 4);
+  let blocks_wide = if (checked_size & 1) != 0 { 2 } else { 1 };
+  let blocks_tall = if (checked_size & 2) != 0 { 2 } else { 1 };
+  let checked_x = const_bound_check(x, 32 * blocks_wide);
+  let checked_y = const_bound_check(y, 32 * blocks_tall);
+  let block = (checked_x / 32) + (checked_y / 32) * blocks_wide;
+  let entry = (checked_y % 32) * 32 + (checked_x % 32);
+  index_screenblock(screenblock + block) + (TEXT_SCREENBLOCK_ENTRY_SIZE * entry)
+}
+
 /// The size of an affine mode screen entry.
 pub const AFFINE_SCREENBLOCK_ENTRY_SIZE: usize = 1;
 
@@ -212,3 +271,45 @@ pub const VRAM_FRAME0_BASE_ADDR: usize = VRAM_BASE_ADDR;
 
 /// Base address of the bitmap frame 1 (video modes 4 or 5).
 pub const VRAM_MODE4_FRAME1_BASE_ADDR: usize = 0x0600_A000;
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_index_tile_4bpp() {
+    let cb = index_bg_charblock(1);
+    assert_eq!(cb.index_tile_4bpp(0), 0x0600_4000);
+    assert_eq!(cb.index_tile_4bpp(1), 0x0600_4020);
+    assert_eq!(cb.index_tile_4bpp(511), 0x0600_7FE0);
+  }
+
+  #[test]
+  fn test_index_tile_8bpp() {
+    let cb = index_bg_charblock(1);
+    assert_eq!(cb.index_tile_8bpp(0), 0x0600_4000);
+    assert_eq!(cb.index_tile_8bpp(1), 0x0600_4040);
+    assert_eq!(cb.index_tile_8bpp(255), 0x0600_7FC0);
+  }
+
+  #[test]
+  fn test_index_screenblock() {
+    assert_eq!(index_screenblock(0), 0x0600_0000);
+    assert_eq!(index_screenblock(1), 0x0600_0800);
+    assert_eq!(index_screenblock(31), 0x0600_F800);
+  }
+
+  #[test]
+  fn test_index_text_screen_entry() {
+    let base = index_screenblock(8);
+    assert_eq!(index_text_screen_entry(8, 0, 0, 0), base);
+    assert_eq!(index_text_screen_entry(8, 0, 31, 1), base + 2 * 63);
+    // 64x64: base, then right, then down, then down-right
+    assert_eq!(index_text_screen_entry(8, 3, 32, 0), base + 0x800);
+    assert_eq!(index_text_screen_entry(8, 3, 0, 32), base + 0x1000);
+    assert_eq!(index_text_screen_entry(8, 3, 33, 33), base + 0x1842);
+    // 64x32 and 32x64 only have two screenblocks each
+    assert_eq!(index_text_screen_entry(8, 1, 32, 31), base + 0x800 + 0x7C0);
+    assert_eq!(index_text_screen_entry(8, 2, 31, 32), base + 0x800 + 0x3E);
+  }
+}
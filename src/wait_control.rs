@@ -0,0 +1,244 @@
+//! Wait state control values.
+//!
+//! The [`WAITCNT_ADDR`] register controls the wait states of `SRAM` and of
+//! each of the three `ROM` mirrors. Each `ROM` mirror has a setting for the
+//! first (non-sequential) access and for the second (sequential) access.
+
+use super::*;
+
+/// One of the three `ROM` mirrors, each with its own wait state settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RomWaitState {
+  /// The mirror at [`ROM_WAIT0_BASE_ADDR`].
+  Ws0,
+  /// The mirror at [`ROM_WAIT1_BASE_ADDR`].
+  Ws1,
+  /// The mirror at [`ROM_WAIT2_BASE_ADDR`].
+  Ws2,
+}
+
+/// Gets the `ROM` wait state settings that govern an address.
+///
+/// Returns `None` if the address isn't within `ROM`.
+pub const fn rom_wait_state_of(addr: usize) -> Option<RomWaitState> {
+  match region_of(addr) {
+    Some(region) => region.rom_wait_state(),
+    None => None,
+  }
+}
+
+impl MemoryRegion {
+  /// The `ROM` wait state settings that govern this region, if any.
+  pub const fn rom_wait_state(self) -> Option<RomWaitState> {
+    match self {
+      MemoryRegion::RomWait0 => Some(RomWaitState::Ws0),
+      MemoryRegion::RomWait1 => Some(RomWaitState::Ws1),
+      MemoryRegion::RomWait2 => Some(RomWaitState::Ws2),
+      _ => None,
+    }
+  }
+}
+
+/// Wait states for the `SRAM` and `ROM` first access settings.
+const FIRST_ACCESS_WAITS: [u32; 4] = [4, 3, 2, 8];
+
+/// A value for [`WAITCNT_ADDR`].
+///
+/// * 0-1: `SRAM` wait (4, 3, 2, or 8 cycles)
+/// * 2-3: Wait state 0 first access (4, 3, 2, or 8 cycles)
+/// * 4: Wait state 0 second access (2 or 1 cycles)
+/// * 5-6: Wait state 1 first access (4, 3, 2, or 8 cycles)
+/// * 7: Wait state 1 second access (4 or 1 cycles)
+/// * 8-9: Wait state 2 first access (4, 3, 2, or 8 cycles)
+/// * 10: Wait state 2 second access (8 or 1 cycles)
+/// * 11-12: PHI terminal output (disabled, 4.19MHz, 8.38MHz, or 16.78MHz)
+/// * 14: Game Pak prefetch buffer enabled
+/// * 15: Game Pak type (read-only, 0 for GBA, 1 for CGB)
+///
+/// The default value is 0, and most carts can use `0x4317`: 3 cycles first
+/// access and 1 cycle second access for wait state 0, with prefetch enabled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct WaitControl(u16);
+impl WaitControl {
+  u16_newtype_basics!();
+
+  /// Info about each field.
+  pub const FIELDS: &'static [FieldInfo] = &[
+    FieldInfo::new("sram", 0, 2),
+    FieldInfo::new("ws0_first", 2, 2),
+    FieldInfo::new("ws0_second", 4, 1),
+    FieldInfo::new("ws1_first", 5, 2),
+    FieldInfo::new("ws1_second", 7, 1),
+    FieldInfo::new("ws2_first", 8, 2),
+    FieldInfo::new("ws2_second", 10, 1),
+    FieldInfo::new("phi_terminal", 11, 2),
+    FieldInfo::new("prefetch", 14, 1),
+    FieldInfo::new("cgb_game_pak", 15, 1),
+  ];
+
+  u16_int_field!(
+    /// The `SRAM` wait setting, 0 through 3.
+    0 - 1,
+    sram,
+    with_sram
+  );
+  u16_int_field!(
+    /// The wait state 0 first access setting, 0 through 3.
+    2 - 3,
+    ws0_first,
+    with_ws0_first
+  );
+  u16_bool_field!(
+    /// The wait state 0 second access setting.
+    4,
+    ws0_second,
+    with_ws0_second
+  );
+  u16_int_field!(
+    /// The wait state 1 first access setting, 0 through 3.
+    5 - 6,
+    ws1_first,
+    with_ws1_first
+  );
+  u16_bool_field!(
+    /// The wait state 1 second access setting.
+    7,
+    ws1_second,
+    with_ws1_second
+  );
+  u16_int_field!(
+    /// The wait state 2 first access setting, 0 through 3.
+    8 - 9,
+    ws2_first,
+    with_ws2_first
+  );
+  u16_bool_field!(
+    /// The wait state 2 second access setting.
+    10,
+    ws2_second,
+    with_ws2_second
+  );
+  u16_int_field!(
+    /// The PHI terminal output setting, 0 through 3.
+    11 - 12,
+    phi_terminal,
+    with_phi_terminal
+  );
+  u16_bool_field!(
+    /// If the Game Pak prefetch buffer is enabled.
+    14,
+    prefetch,
+    with_prefetch
+  );
+  u16_bool_field!(
+    /// If the inserted Game Pak is a CGB cart.
+    15,
+    cgb_game_pak,
+    with_cgb_game_pak
+  );
+
+  /// The number of wait states for an `SRAM` access.
+  pub const fn sram_waits(self) -> u32 {
+    FIRST_ACCESS_WAITS[self.sram() as usize]
+  }
+
+  /// The number of wait states for the first (non-sequential) access to a
+  /// `ROM` mirror.
+  pub const fn first_access_waits(self, ws: RomWaitState) -> u32 {
+    let setting = match ws {
+      RomWaitState::Ws0 => self.ws0_first(),
+      RomWaitState::Ws1 => self.ws1_first(),
+      RomWaitState::Ws2 => self.ws2_first(),
+    };
+    FIRST_ACCESS_WAITS[setting as usize]
+  }
+
+  /// The number of wait states for the second (sequential) access to a `ROM`
+  /// mirror.
+  pub const fn second_access_waits(self, ws: RomWaitState) -> u32 {
+    let (fast, slow) = match ws {
+      RomWaitState::Ws0 => (self.ws0_second(), 2),
+      RomWaitState::Ws1 => (self.ws1_second(), 4),
+      RomWaitState::Ws2 => (self.ws2_second(), 8),
+    };
+    if fast {
+      1
+    } else {
+      slow
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_wait_control_fields() {
+    let w = WaitControl::from_u16(0x4317);
+    assert_eq!(w.sram(), 3);
+    assert_eq!(w.ws0_first(), 1);
+    assert!(w.ws0_second());
+    assert_eq!(w.ws1_first(), 0);
+    assert!(!w.ws1_second());
+    assert_eq!(w.ws2_first(), 3);
+    assert!(!w.ws2_second());
+    assert_eq!(w.phi_terminal(), 0);
+    assert!(w.prefetch());
+    assert!(!w.cgb_game_pak());
+
+    let built = WaitControl::new()
+      .with_sram(3)
+      .with_ws0_first(1)
+      .with_ws0_second(true)
+      .with_ws2_first(3)
+      .with_prefetch(true);
+    assert_eq!(built.to_u16(), 0x4317);
+
+    assert_eq!(WaitControl::new().with_ws1_first(3).to_u16(), 0x0060);
+    assert_eq!(WaitControl::new().with_ws1_second(true).to_u16(), 0x0080);
+    assert_eq!(WaitControl::new().with_ws2_second(true).to_u16(), 0x0400);
+    assert_eq!(WaitControl::new().with_phi_terminal(3).to_u16(), 0x1800);
+    assert_eq!(WaitControl::new().with_cgb_game_pak(true).to_u16(), 0x8000);
+  }
+
+  #[test]
+  fn test_wait_control_waits() {
+    use RomWaitState::*;
+    let boot = WaitControl::new();
+    assert_eq!(boot.sram_waits(), 4);
+    assert_eq!(boot.first_access_waits(Ws0), 4);
+    assert_eq!(boot.second_access_waits(Ws0), 2);
+    assert_eq!(boot.second_access_waits(Ws1), 4);
+    assert_eq!(boot.second_access_waits(Ws2), 8);
+
+    let fast = WaitControl::from_u16(0x4317);
+    assert_eq!(fast.sram_waits(), 8);
+    assert_eq!(fast.first_access_waits(Ws0), 3);
+    assert_eq!(fast.second_access_waits(Ws0), 1);
+    assert_eq!(fast.first_access_waits(Ws1), 4);
+    assert_eq!(fast.second_access_waits(Ws1), 4);
+    assert_eq!(fast.first_access_waits(Ws2), 8);
+
+    for &(setting, waits) in &[(0, 4), (1, 3), (2, 2), (3, 8)] {
+      let w = WaitControl::new().with_ws1_first(setting);
+      assert_eq!(w.first_access_waits(Ws1), waits);
+    }
+    let all_fast = WaitControl::new()
+      .with_ws0_second(true)
+      .with_ws1_second(true)
+      .with_ws2_second(true);
+    for &ws in &[Ws0, Ws1, Ws2] {
+      assert_eq!(all_fast.second_access_waits(ws), 1);
+    }
+  }
+
+  #[test]
+  fn test_rom_wait_state_of() {
+    assert_eq!(rom_wait_state_of(0x0800_0000), Some(RomWaitState::Ws0));
+    assert_eq!(rom_wait_state_of(0x0BFF_FFFF), Some(RomWaitState::Ws1));
+    assert_eq!(rom_wait_state_of(0x0C00_0000), Some(RomWaitState::Ws2));
+    assert_eq!(rom_wait_state_of(0x0E00_0000), None);
+  }
+}